@@ -12,6 +12,7 @@ fn main() {
         head_noun: "lorica".into(),
         adjective: vec!["hamatus".into(), "grandis".into()],
         adposition_noun: vec!["manica".into()],
+        ..Default::default()
     };
 
     // Inflect the complex noun as so. Returns a String
@@ -25,11 +26,33 @@ fn main() {
     println!("{:#?}", noun.0);
     //Output: "agricolas"
 
-    //Adjectives are similar to nouns, but require an additional Gender argument. Returns an inflected String
-    let adj = inflector.adjective("integer", &Case::Nom, &Number::Singular, &Gender::Feminine);
+    //Adjectives are similar to nouns, but require additional Gender and Degree arguments. Returns an inflected String
+    let adj = inflector.adjective(
+        "integer",
+        &Case::Nom,
+        &Number::Singular,
+        &Gender::Feminine,
+        &Degree::Positive,
+    );
     println!("{:#?}", adj);
     //Output: "integra"
 
+    // Comparatives and superlatives are declined as well, irregular ones included
+    let comparative = inflector.adjective(
+        "magnus",
+        &Case::Abl,
+        &Number::Plural,
+        &Gender::Neuter,
+        &Degree::Comparative,
+    );
+    println!("{:#?}", comparative);
+    //Output: "maioribus"
+
+    // Adverbs are formed from adjectives in any degree
+    let adverb = inflector.adverb("brevis", &Degree::Positive);
+    println!("{:#?}", adverb);
+    //Output: "breviter"
+
     //You can guess nouns and adjectives without instantiating the conjugator with dictionaries if you so desire.
     // But instantiating with the csv dictionaries gives a superior result.
     let guessed_adjective = Latin::guess_adjective(
@@ -14,6 +14,7 @@ fn main() {
         head_noun: "lorica".into(),
         adjective: vec!["hamatus".into(), "segmentus".into()],
         adposition_noun: vec!["manica".into()],
+        ..Default::default()
     };
     let comp2 = conji.complex_noun(&complexik, &Case::Gen, &Number::Plural);
     println!("{:#?}", comp2);
@@ -81,7 +82,7 @@ fn main() {
     }
     for wot in testik2 {
         println!("adj : {:#?}", wot);
-        let new_noun = conji.adjective(&wot.0, &Case::Gen, &Number::Singular, &Gender::Feminine);
+        let new_noun = conji.adjective(&wot.0, &Case::Gen, &Number::Singular, &Gender::Feminine, &Degree::Positive);
         println!("adj : {:#?}", new_noun);
     }
     for wot in testik3 {
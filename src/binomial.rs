@@ -0,0 +1,161 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum EpithetKind {
+    // Agrees with the genus in gender, "Acer rubrum"
+    Adjectival,
+    // Noun in apposition keeping its own gender, "Pyrus malus"
+    Apposition,
+    // Noun in the genitive, "Rosa hookeri"
+    Genitive,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Epithet {
+    // Adjectival epithets are given by their masculine lemma, "ruber",
+    // the others exactly as they are written
+    pub word: String,
+    pub kind: EpithetKind,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InfraspecificRank {
+    Subspecies,
+    Variety,
+    Subvariety,
+    Form,
+}
+
+impl InfraspecificRank {
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            InfraspecificRank::Subspecies => "subsp.",
+            InfraspecificRank::Variety => "var.",
+            InfraspecificRank::Subvariety => "subvar.",
+            InfraspecificRank::Form => "f.",
+        }
+    }
+}
+
+// "Acer rubrum", "Acer saccharum subsp. nigrum"
+#[derive(Debug, PartialEq, Clone)]
+pub struct BinomialName {
+    pub genus: String,
+    pub epithet: Epithet,
+    pub infraspecific: Option<(InfraspecificRank, Epithet)>,
+}
+
+impl BinomialName {
+    // The same epithets under another genus, adjectival ones follow its gender when rendered
+    pub fn transfer(&self, genus: &str) -> BinomialName {
+        BinomialName {
+            genus: genus.to_string(),
+            ..self.clone()
+        }
+    }
+}
+
+impl Latin {
+    // The epithet as it stands after the given genus
+    pub fn epithet(&self, epithet: &Epithet, genus: &str) -> String {
+        self.epithet_case(epithet, genus, &Case::Nom)
+    }
+
+    // Adjectival epithets agree with the genus in case as well, nouns in apposition are
+    // declined on their own and genitives stay as they are
+    pub fn epithet_case(&self, epithet: &Epithet, genus: &str, case: &Case) -> String {
+        let word = epithet.word.to_lowercase();
+
+        match epithet.kind {
+            EpithetKind::Adjectival => {
+                let gender = self.genus_gender(genus).gender;
+                self.adjective(&word, case, &Number::Singular, &gender, &Degree::Positive)
+            }
+            EpithetKind::Apposition if case != &Case::Nom => {
+                self.noun(&word, case, &Number::Singular).0
+            }
+            _ => word,
+        }
+    }
+
+    pub fn binomial(&self, name: &BinomialName) -> String {
+        self.binomial_case(name, &Case::Nom, false)
+    }
+
+    // The name in the given case, "Acere rubro". An abbreviated genus is left undeclined, "A. rubro"
+    pub fn binomial_case(&self, name: &BinomialName, case: &Case, abbreviated: bool) -> String {
+        let mut genus = name.genus.to_lowercase();
+        let epithet = self.epithet_case(&name.epithet, &genus, case);
+        let infraspecific = name
+            .infraspecific
+            .as_ref()
+            .map(|(rank, epithet)| (rank, self.epithet_case(epithet, &genus, case)));
+
+        if abbreviated {
+            genus = genus.chars().take(1).collect::<String>() + ".";
+        } else if case != &Case::Nom {
+            genus = self.noun(&genus, case, &Number::Singular).0;
+        }
+        if let Some(first) = genus.get(0..1) {
+            genus = format!("{}{}", first.to_uppercase(), &genus[1..]);
+        }
+
+        let mut response = format!("{} {}", genus, epithet);
+
+        if let Some((rank, epithet)) = infraspecific {
+            response = format!("{} {} {}", response, rank.abbreviation(), epithet);
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    fn adjectival(word: &str) -> Epithet {
+        Epithet {
+            word: word.to_string(),
+            kind: EpithetKind::Adjectival,
+        }
+    }
+
+    fn acer_rubrum() -> BinomialName {
+        BinomialName {
+            genus: "Acer".to_string(),
+            epithet: adjectival("ruber"),
+            infraspecific: Some((InfraspecificRank::Variety, adjectival("tomentosus"))),
+        }
+    }
+
+    #[test]
+    fn adjectival_epithets_agree_with_genus() {
+        assert_eq!(
+            latin().binomial(&acer_rubrum()),
+            "Acer rubrum var. tomentosum"
+        );
+    }
+
+    #[test]
+    fn transfer_follows_new_genus() {
+        assert_eq!(
+            latin().binomial(&acer_rubrum().transfer("Quercus")),
+            "Quercus rubra var. tomentosa"
+        );
+    }
+
+    #[test]
+    fn genitive_epithet_is_kept() {
+        let name = BinomialName {
+            genus: "Rosa".to_string(),
+            epithet: Epithet {
+                word: "hookeri".to_string(),
+                kind: EpithetKind::Genitive,
+            },
+            infraspecific: None,
+        };
+        assert_eq!(latin().binomial_case(&name, &Case::Abl, true), "R. hookeri");
+    }
+}
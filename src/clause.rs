@@ -0,0 +1,184 @@
+use crate::*;
+
+// A simple descriptive clause, "Folia sunt ovata", "Planta floret mense Maio"
+#[derive(Debug, Clone)]
+pub struct Clause {
+    // Left out of the sentence when the head noun is empty, as Latin drops pronoun subjects
+    pub subject: ComplexNoun,
+    pub number: Number,
+    // Only for subjects left out, a noun subject is always in the third person
+    pub person: Person,
+    // Verb as found in the verb dictionary, "floreo" or "floreo_". Without a verb the
+    // copula is used for predicate adjectives
+    pub verb: Option<String>,
+    pub tense: Tense,
+    // Agree with the subject in gender and number, "ovata"
+    pub predicate_adjective: Vec<String>,
+    // Rendered in the accusative
+    pub object: Option<(ComplexNoun, Number)>,
+    // Phrases in a case of their own after everything else, "mense Maio"
+    pub adjunct: Vec<(ComplexNoun, Case, Number)>,
+}
+
+impl Default for Clause {
+    fn default() -> Self {
+        Self {
+            subject: ComplexNoun::default(),
+            number: Number::Singular,
+            person: Person::Third,
+            verb: None,
+            tense: Tense::Present,
+            predicate_adjective: Vec::new(),
+            object: None,
+            adjunct: Vec::new(),
+        }
+    }
+}
+
+impl Latin {
+    // Renders the clause as a sentence, capitalized and ending in a full stop. None when the
+    // verb is not in the verb dictionary
+    pub fn clause(&self, clause: &Clause) -> Option<String> {
+        let mut words = Vec::new();
+
+        let mut agreement = clause.subject.agreement_gender.clone().unwrap_or_default();
+        if !clause.subject.head_noun.is_empty() {
+            words.push(self.complex_noun(&clause.subject, &Case::Nom, &clause.number));
+            agreement = self.agreement_gender(&clause.subject);
+        }
+
+        let verb = match &clause.verb {
+            Some(verb) => Some(verb.as_str()),
+            None if !clause.predicate_adjective.is_empty() => Some("sum"),
+            None => None,
+        };
+        if let Some(verb) = verb {
+            let verb = self.verb_id(verb);
+            if !self.verb_map.contains_key(&verb) {
+                return None;
+            }
+            let person = match clause.subject.head_noun.is_empty() {
+                true => &clause.person,
+                false => &Person::Third,
+            };
+            words.push(self.verb(
+                &verb,
+                &Mood::Indicative,
+                &Voice::Active,
+                &clause.tense,
+                &clause.number,
+                person,
+            ));
+        }
+
+        let predicate: Vec<&str> = clause
+            .predicate_adjective
+            .iter()
+            .map(|adj| adj.as_str())
+            .collect();
+        words.push(self.coordinate_adjectives(
+            &predicate,
+            &Conjunction::Et,
+            &Case::Nom,
+            &clause.number,
+            &agreement,
+        ));
+
+        if let Some((object, number)) = &clause.object {
+            words.push(self.complex_noun(object, &Case::Acc, number));
+        }
+
+        for (adjunct, case, number) in &clause.adjunct {
+            words.push(self.complex_noun(adjunct, case, number));
+        }
+
+        Some(Latin::sentence(&words))
+    }
+
+    // Key of a verb in the verb dictionary, looked up by its first principal part if need be
+    pub fn verb_id(&self, verb: &str) -> String {
+        if self.verb_map.contains_key(verb) {
+            return verb.to_string();
+        }
+
+        self.verb_map
+            .values()
+            .filter(|record| record.canonical == verb)
+            .map(|record| record.word.clone())
+            .min()
+            .unwrap_or(verb.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    #[test]
+    fn future_perfect_is_built_on_the_perfect_stem() {
+        let clause = Clause {
+            verb: Some("floreo".to_string()),
+            tense: Tense::FuturePerfect,
+            number: Number::Plural,
+            ..Default::default()
+        };
+        assert_eq!(
+            latin().clause(&clause),
+            Some("Exempla floruerint.".to_string())
+        );
+    }
+
+    #[test]
+    fn future_differs_from_the_future_perfect() {
+        let subject = ComplexNoun {
+            head_noun: "planta".to_string(),
+            ..Default::default()
+        };
+        let clause = |tense| Clause {
+            subject: subject.clone(),
+            verb: Some("habeo".to_string()),
+            tense,
+            object: Some((
+                ComplexNoun {
+                    head_noun: "folium".to_string(),
+                    ..Default::default()
+                },
+                Number::Plural,
+            )),
+            ..Default::default()
+        };
+        assert_eq!(
+            latin().clause(&clause(Tense::Future)),
+            Some("Planta habebit folia.".to_string())
+        );
+        assert_eq!(
+            latin().clause(&clause(Tense::FuturePerfect)),
+            Some("Planta habuerit folia.".to_string())
+        );
+    }
+
+    #[test]
+    fn noun_subjects_are_in_the_third_person() {
+        let clause = Clause {
+            subject: ComplexNoun {
+                head_noun: "flos".to_string(),
+                ..Default::default()
+            },
+            number: Number::Plural,
+            person: Person::First,
+            verb: Some("floreo".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(latin().clause(&clause), Some("Flores florent.".to_string()));
+    }
+
+    #[test]
+    fn unknown_verb_is_reported() {
+        let clause = Clause {
+            verb: Some("nonverbum".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(latin().clause(&clause), None);
+    }
+}
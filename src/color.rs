@@ -0,0 +1,242 @@
+use crate::*;
+
+pub type Rgb = (u8, u8, u8);
+
+// Base colors: adjective, its form in -escens when irregular, English names separated by ";"
+// and a typical RGB value
+pub const BASE_COLORS: [(&str, &str, &str, Rgb); 24] = [
+    ("albus", "", "white", (255, 255, 255)),
+    ("lacteus", "", "milky white;milk white", (250, 248, 230)),
+    ("niger", "", "black", (0, 0, 0)),
+    ("griseus", "", "grey;gray", (128, 128, 128)),
+    ("cinereus", "cinerascens", "ash grey;ashen", (178, 178, 168)),
+    ("argenteus", "", "silver", (192, 192, 192)),
+    ("ruber", "rubescens", "red", (200, 30, 30)),
+    ("coccineus", "", "scarlet", (255, 36, 0)),
+    ("sanguineus", "", "blood red", (138, 7, 7)),
+    ("roseus", "", "pink;rose", (255, 160, 190)),
+    ("carneus", "", "flesh;flesh-colored", (255, 205, 180)),
+    ("purpureus", "purpurascens", "purple", (128, 0, 128)),
+    ("violaceus", "violascens", "violet", (140, 80, 200)),
+    ("lilacinus", "", "lilac", (200, 162, 200)),
+    ("caeruleus", "caerulescens", "blue", (40, 90, 200)),
+    ("viridis", "virescens", "green", (40, 140, 40)),
+    ("glaucus", "", "glaucous;blue-green", (96, 150, 160)),
+    ("flavus", "", "yellow", (250, 220, 40)),
+    ("luteus", "", "deep yellow", (240, 180, 0)),
+    ("aureus", "", "golden;gold", (255, 200, 0)),
+    ("stramineus", "", "straw;straw-colored", (228, 217, 140)),
+    ("aurantiacus", "", "orange", (250, 140, 0)),
+    ("ochraceus", "", "ochre;ocher", (204, 119, 34)),
+    ("brunneus", "", "brown", (120, 70, 30)),
+];
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ColorModifier {
+    // Adverbs before the color, "pallide caeruleus"
+    Pallide,
+    Intense,
+    Obscure,
+    Dilute,
+    Sordide,
+    // Prefixes joined to the color, "atropurpureus", "subalbus"
+    Atro,
+    Sub,
+}
+
+impl ColorModifier {
+    fn adverb(&self) -> Option<&'static str> {
+        match self {
+            ColorModifier::Pallide => Some("pallide"),
+            ColorModifier::Intense => Some("intense"),
+            ColorModifier::Obscure => Some("obscure"),
+            ColorModifier::Dilute => Some("dilute"),
+            ColorModifier::Sordide => Some("sordide"),
+            ColorModifier::Atro | ColorModifier::Sub => None,
+        }
+    }
+
+    fn prefix(&self) -> Option<&'static str> {
+        match self {
+            ColorModifier::Atro => Some("atro"),
+            ColorModifier::Sub => Some("sub"),
+            _ => None,
+        }
+    }
+}
+
+// "albo-roseus", "viridi-flavescens", "pallide caeruleus"
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Color {
+    // Colors as masculine lemmas, all but the last stay fixed in the compound
+    pub colors: Vec<String>,
+    // The last color in -escens, "turning" or "somewhat", "flavescens"
+    pub escens: bool,
+    pub modifier: Option<ColorModifier>,
+}
+
+impl Latin {
+    // Form of a color as the first element of a compound, -o for colors of the second
+    // declension, -i for those of the third, "albo-", "rubro-", "viridi-"
+    pub fn color_combining_form(&self, color: &str) -> String {
+        let genitive = self.adjective(
+            color,
+            &Case::Gen,
+            &Number::Singular,
+            &Gender::Masculine,
+            &Degree::Positive,
+        );
+
+        match genitive.strip_suffix("is") {
+            Some(stem) => format!("{stem}i"),
+            None => match genitive.strip_suffix('i') {
+                Some(stem) => format!("{stem}o"),
+                None => genitive,
+            },
+        }
+    }
+
+    // "flavescens", "rubescens", irregular ones are taken from the color list
+    pub fn color_escens(&self, color: &str) -> String {
+        if let Some((_, escens, _, _)) = BASE_COLORS.iter().find(|(c, ..)| *c == color) {
+            if !escens.is_empty() {
+                return escens.to_string();
+            }
+        }
+
+        // "luteus" gives "lutescens", the e of -eus goes as well
+        let combining = self.color_combining_form(color);
+        let stem = combining.strip_suffix(['o', 'i']).unwrap_or(&combining);
+        let stem = stem.strip_suffix('e').unwrap_or(stem);
+        format!("{stem}escens")
+    }
+
+    pub fn color(&self, color: &Color, case: &Case, number: &Number, gender: &Gender) -> String {
+        let Some((last, first)) = color.colors.split_last() else {
+            return String::new();
+        };
+
+        let mut last = match color.escens {
+            true => self.color_escens(last),
+            false => last.clone(),
+        };
+        last = self.adjective(&last, case, number, gender, &Degree::Positive);
+
+        let mut elements: Vec<String> =
+            first.iter().map(|c| self.color_combining_form(c)).collect();
+        elements.push(last);
+        let mut response = elements.join("-");
+
+        if let Some(modifier) = &color.modifier {
+            if let Some(prefix) = modifier.prefix() {
+                response = format!("{prefix}{response}");
+            }
+            if let Some(adverb) = modifier.adverb() {
+                response = format!("{adverb} {response}");
+            }
+        }
+
+        response
+    }
+
+    // "pink" to "roseus"
+    pub fn color_by_english(english: &str) -> Option<&'static str> {
+        let english = english.trim().to_lowercase();
+
+        BASE_COLORS
+            .iter()
+            .find(|(_, _, names, _)| names.split(';').any(|name| name == english))
+            .map(|(color, ..)| *color)
+    }
+
+    // The base color nearest to the given one
+    pub fn color_by_rgb(red: u8, green: u8, blue: u8) -> &'static str {
+        let distance = |(r, g, b): Rgb| {
+            (r as i32 - red as i32).pow(2)
+                + (g as i32 - green as i32).pow(2)
+                + (b as i32 - blue as i32).pow(2)
+        };
+
+        BASE_COLORS
+            .iter()
+            .min_by_key(|(_, _, _, rgb)| distance(*rgb))
+            .map(|(color, ..)| *color)
+            .unwrap_or("albus")
+    }
+
+    // "#8b0000" or "8b0000", None when it is not a hex color
+    pub fn color_by_hex(hex: &str) -> Option<&'static str> {
+        let hex = hex.trim().trim_start_matches('#');
+        if hex.len() != 6 {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        Some(Latin::color_by_rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    fn colors(colors: &[&str], modifier: Option<ColorModifier>) -> Color {
+        Color {
+            colors: colors.iter().map(|c| c.to_string()).collect(),
+            modifier,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn compound_colors() {
+        let latin = latin();
+        let albo_roseus = colors(&["albus", "roseus"], None);
+        assert_eq!(
+            latin.color(
+                &albo_roseus,
+                &Case::Nom,
+                &Number::Singular,
+                &Gender::Masculine
+            ),
+            "albo-roseus"
+        );
+        assert_eq!(
+            latin.color(&albo_roseus, &Case::Abl, &Number::Plural, &Gender::Feminine),
+            "albo-roseis"
+        );
+    }
+
+    #[test]
+    fn prefixed_and_adverbial_modifiers() {
+        let latin = latin();
+        assert_eq!(
+            latin.color(
+                &colors(&["purpureus"], Some(ColorModifier::Atro)),
+                &Case::Nom,
+                &Number::Singular,
+                &Gender::Masculine
+            ),
+            "atropurpureus"
+        );
+        assert_eq!(
+            latin.color(
+                &colors(&["caeruleus"], Some(ColorModifier::Pallide)),
+                &Case::Nom,
+                &Number::Singular,
+                &Gender::Feminine
+            ),
+            "pallide caerulea"
+        );
+    }
+
+    #[test]
+    fn colors_by_name_and_value() {
+        assert_eq!(Latin::color_by_english("Pink"), Some("roseus"));
+        assert_eq!(Latin::color_by_hex("#8b0000"), Some("sanguineus"));
+        assert_eq!(Latin::color_by_hex("8b0000"), Some("sanguineus"));
+        assert_eq!(Latin::color_by_hex("#8b00"), None);
+        assert_eq!(Latin::color_by_hex("#zz0000"), None);
+    }
+}
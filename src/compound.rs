@@ -0,0 +1,149 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Origin {
+    // Joined with "-i-", "latifolius"
+    Latin,
+    // Joined with "-o-", "macrocarpus"
+    Greek,
+}
+
+// First elements of compounds: adjective, its combining stem and the language it comes from
+pub const COMPOUND_FIRST_ELEMENTS: [(&str, &str, Origin); 36] = [
+    ("acutus", "acut", Origin::Latin),
+    ("albus", "alb", Origin::Latin),
+    ("angustus", "angust", Origin::Latin),
+    ("brevis", "brev", Origin::Latin),
+    ("crassus", "crass", Origin::Latin),
+    ("flavus", "flav", Origin::Latin),
+    ("grandis", "grand", Origin::Latin),
+    ("latus", "lat", Origin::Latin),
+    ("longus", "long", Origin::Latin),
+    ("magnus", "magn", Origin::Latin),
+    ("multus", "mult", Origin::Latin),
+    ("niger", "nigr", Origin::Latin),
+    ("parvus", "parv", Origin::Latin),
+    ("paucus", "pauc", Origin::Latin),
+    ("rotundus", "rotund", Origin::Latin),
+    ("ruber", "rubr", Origin::Latin),
+    ("tenuis", "tenu", Origin::Latin),
+    ("anisos", "anis", Origin::Greek),
+    ("brachys", "brachy", Origin::Greek),
+    ("chrysos", "chrys", Origin::Greek),
+    ("dasys", "dasy", Origin::Greek),
+    ("erythros", "erythr", Origin::Greek),
+    ("heteros", "heter", Origin::Greek),
+    ("leptos", "lept", Origin::Greek),
+    ("leucos", "leuc", Origin::Greek),
+    ("macros", "macr", Origin::Greek),
+    ("megas", "megal", Origin::Greek),
+    ("melas", "melan", Origin::Greek),
+    ("micros", "micr", Origin::Greek),
+    ("oligos", "olig", Origin::Greek),
+    ("pachys", "pachy", Origin::Greek),
+    ("platys", "platy", Origin::Greek),
+    ("polys", "poly", Origin::Greek),
+    ("stenos", "sten", Origin::Greek),
+    ("trachys", "trachy", Origin::Greek),
+    ("xanthos", "xanth", Origin::Greek),
+];
+
+// Second elements of compounds: noun, the masculine adjective ending it gives and the
+// language it comes from, "folium" gives "-folius"
+pub const COMPOUND_SECOND_ELEMENTS: [(&str, &str, Origin); 13] = [
+    ("folium", "folius", Origin::Latin),
+    ("flos", "florus", Origin::Latin),
+    ("caulis", "caulis", Origin::Latin),
+    ("spina", "spinus", Origin::Latin),
+    ("anthos", "anthus", Origin::Greek),
+    ("carpos", "carpus", Origin::Greek),
+    ("cephale", "cephalus", Origin::Greek),
+    ("petalon", "petalus", Origin::Greek),
+    ("phyllon", "phyllus", Origin::Greek),
+    ("pous", "podus", Origin::Greek),
+    ("sepalum", "sepalus", Origin::Greek),
+    ("sperma", "spermus", Origin::Greek),
+    ("stachys", "stachyus", Origin::Greek),
+];
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompoundEpithet {
+    // Masculine lemma for Latin::adjective, "parviflorus"
+    pub word: String,
+    // The elements come from different languages, as in "macrofolius", which is
+    // legitimate but frowned upon
+    pub hybrid: bool,
+}
+
+impl Latin {
+    // Combining stem and origin of a first element, given as the adjective or the stem itself
+    pub fn compound_first_element(first: &str) -> Option<(&'static str, Origin)> {
+        COMPOUND_FIRST_ELEMENTS
+            .iter()
+            .find(|(lemma, stem, _)| *lemma == first || *stem == first)
+            .map(|(_, stem, origin)| (*stem, origin.clone()))
+    }
+
+    // Adjective ending and origin of a second element, given as the noun or the ending itself
+    pub fn compound_second_element(second: &str) -> Option<(&'static str, Origin)> {
+        COMPOUND_SECOND_ELEMENTS
+            .iter()
+            .find(|(noun, ending, _)| *noun == second || *ending == second)
+            .map(|(_, ending, origin)| (*ending, origin.clone()))
+    }
+
+    // "latus" and "folium" give "latifolius", "macros" and "anthos" give "macranthus".
+    // The connecting vowel follows the first element and is left out before a vowel
+    // or after a stem in -y. None when either element is unknown
+    pub fn compound_epithet(first: &str, second: &str) -> Option<CompoundEpithet> {
+        let (stem, first_origin) = Latin::compound_first_element(first)?;
+        let (second, second_origin) = Latin::compound_second_element(second)?;
+
+        let connecting =
+            if second.starts_with(['a', 'e', 'i', 'o', 'u', 'y']) || stem.ends_with('y') {
+                ""
+            } else {
+                match first_origin {
+                    Origin::Latin => "i",
+                    Origin::Greek => "o",
+                }
+            };
+
+        Some(CompoundEpithet {
+            word: format!("{stem}{connecting}{second}"),
+            hybrid: first_origin != second_origin,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compound(first: &str, second: &str) -> Option<String> {
+        Latin::compound_epithet(first, second).map(|compound| compound.word)
+    }
+
+    #[test]
+    fn compounds_from_nouns() {
+        assert_eq!(compound("latus", "folium"), Some("latifolius".to_string()));
+        assert_eq!(
+            compound("grandis", "flos"),
+            Some("grandiflorus".to_string())
+        );
+        assert_eq!(compound("macros", "anthos"), Some("macranthus".to_string()));
+        assert_eq!(
+            compound("platys", "phyllon"),
+            Some("platyphyllus".to_string())
+        );
+        assert_eq!(compound("lat", "folius"), Some("latifolius".to_string()));
+        assert_eq!(compound("latus", "radix"), None);
+    }
+
+    #[test]
+    fn hybrid_compounds() {
+        let compound = Latin::compound_epithet("macros", "folium").unwrap();
+        assert_eq!(compound.word, "macrofolius");
+        assert!(compound.hybrid);
+    }
+}
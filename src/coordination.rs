@@ -0,0 +1,155 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Conjunction {
+    Et,
+    // "atque" before vowels, h and gutturals, "ac" elsewhere
+    Atque,
+    // Enclitic, attached to the first word of the last member, "petiolisque"
+    Que,
+    Aut,
+    Vel,
+    // Enclitic like -que, "foliisve"
+    Ve,
+    // Repeated before every member, "nec foliis nec caulibus"
+    Nec,
+}
+
+impl Latin {
+    // "foliis, caulibus petiolisque"
+    pub fn coordinate(
+        &self,
+        phrases: &[(ComplexNoun, Number)],
+        conjunction: &Conjunction,
+        case: &Case,
+    ) -> String {
+        let members: Vec<String> = phrases
+            .iter()
+            .map(|(phrase, number)| self.complex_noun(phrase, case, number))
+            .collect();
+
+        Latin::join_coordinated(&members, conjunction)
+    }
+
+    // "glabris vel pubescentibus"
+    pub fn coordinate_adjectives(
+        &self,
+        adjectives: &[&str],
+        conjunction: &Conjunction,
+        case: &Case,
+        number: &Number,
+        gender: &Gender,
+    ) -> String {
+        let members: Vec<String> = adjectives
+            .iter()
+            .map(|adj| self.adjective(adj, case, number, gender, &Degree::Positive))
+            .collect();
+
+        Latin::join_coordinated(&members, conjunction)
+    }
+
+    // Joins already inflected members, the ones before the last are separated by commas
+    pub fn join_coordinated(members: &[String], conjunction: &Conjunction) -> String {
+        let members: Vec<&String> = members.iter().filter(|m| !m.is_empty()).collect();
+
+        if conjunction == &Conjunction::Nec {
+            let members: Vec<String> = members.iter().map(|m| format!("nec {m}")).collect();
+            return members.join(" ");
+        }
+
+        let (last, rest) = match members.split_last() {
+            Some((last, rest)) if !rest.is_empty() => (last, rest),
+            Some((last, _)) => return last.to_string(),
+            None => return String::new(),
+        };
+
+        let rest: Vec<&str> = rest.iter().map(|m| m.as_str()).collect();
+        let rest = rest.join(", ");
+
+        match conjunction {
+            Conjunction::Que | Conjunction::Ve => {
+                let enclitic = Latin::conjunction_form(conjunction, last);
+                let last = match last.split_once(' ') {
+                    Some((first, others)) => format!("{first}{enclitic} {others}"),
+                    None => format!("{last}{enclitic}"),
+                };
+                format!("{rest} {last}")
+            }
+            _ => format!(
+                "{rest} {} {last}",
+                Latin::conjunction_form(conjunction, last)
+            ),
+        }
+    }
+
+    pub fn conjunction_form(conjunction: &Conjunction, next_word: &str) -> &'static str {
+        match conjunction {
+            Conjunction::Et => "et",
+            Conjunction::Atque => {
+                let atque = next_word
+                    .to_lowercase()
+                    .starts_with(['a', 'e', 'i', 'o', 'u', 'y', 'h', 'c', 'g', 'q']);
+                if atque {
+                    "atque"
+                } else {
+                    "ac"
+                }
+            }
+            Conjunction::Que => "que",
+            Conjunction::Aut => "aut",
+            Conjunction::Vel => "vel",
+            Conjunction::Ve => "ve",
+            Conjunction::Nec => "nec",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    fn plural(head_noun: &str) -> (ComplexNoun, Number) {
+        let phrase = ComplexNoun {
+            head_noun: head_noun.to_string(),
+            ..Default::default()
+        };
+        (phrase, Number::Plural)
+    }
+
+    #[test]
+    fn enclitic_que_on_last_member() {
+        let phrases = [plural("folium"), plural("caulis"), plural("petiolus")];
+        assert_eq!(
+            latin().coordinate(&phrases, &Conjunction::Que, &Case::Abl),
+            "foliis, caulibus petiolisque"
+        );
+    }
+
+    #[test]
+    fn nec_before_every_member() {
+        let phrases = [plural("folium"), plural("caulis")];
+        assert_eq!(
+            latin().coordinate(&phrases, &Conjunction::Nec, &Case::Abl),
+            "nec foliis nec caulibus"
+        );
+    }
+
+    #[test]
+    fn atque_and_ac() {
+        assert_eq!(
+            Latin::conjunction_form(&Conjunction::Atque, "ovatis"),
+            "atque"
+        );
+        assert_eq!(Latin::conjunction_form(&Conjunction::Atque, "latis"), "ac");
+    }
+
+    #[test]
+    fn single_member_has_no_conjunction() {
+        let members = vec!["foliis".to_string()];
+        assert_eq!(
+            Latin::join_coordinated(&members, &Conjunction::Et),
+            "foliis"
+        );
+    }
+}
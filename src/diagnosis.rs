@@ -0,0 +1,237 @@
+use crate::*;
+
+#[derive(Debug, Clone)]
+pub enum TemplatePart {
+    // Rendered as it is, "pedicellis" or "saepe"
+    Text(String),
+    // An organ in the given case and number, the traits after it agree with it
+    Organ(ComplexNoun, Case, Number),
+    // An adjective agreeing with the last organ, traits in a row are separated by commas
+    Trait(String),
+    // Organ and traits in the ablative, "foliis deciduis"
+    AblativeAbsolute(ComplexNoun, Number, Vec<String>),
+    // "a speciebus affinibus differt", the taxon in the ablative after "a" or "ab"
+    Differt(ComplexNoun, Number),
+    // Ends a sentence, the next one starts with a capital
+    Stop,
+}
+
+// Trait data for one organ, "folia ovata, glabra"
+#[derive(Debug, Clone)]
+pub struct OrganDescription {
+    pub organ: ComplexNoun,
+    pub number: Number,
+    // Shape, size, color, indumentum and the like, as masculine lemmas
+    pub traits: Vec<String>,
+}
+
+impl Latin {
+    // Renders a template as a paragraph of sentences
+    pub fn diagnosis(&self, template: &[TemplatePart]) -> String {
+        let mut sentences = Vec::new();
+        let mut words: Vec<String> = Vec::new();
+
+        let mut agreement = (Gender::default(), Case::Nom, Number::Singular);
+        let mut after_trait = false;
+
+        for part in template {
+            match part {
+                TemplatePart::Text(text) => words.push(text.clone()),
+                TemplatePart::Organ(organ, case, number) => {
+                    words.push(self.complex_noun(organ, case, number));
+                    agreement = (self.agreement_gender(organ), case.clone(), number.clone());
+                }
+                TemplatePart::Trait(word) => {
+                    if after_trait {
+                        if let Some(last) = words.last_mut() {
+                            last.push(',');
+                        }
+                    }
+                    let (gender, case, number) = &agreement;
+                    words.push(self.adjective(word, case, number, gender, &Degree::Positive));
+                }
+                TemplatePart::AblativeAbsolute(organ, number, traits) => {
+                    words.push(self.complex_noun(organ, &Case::Abl, number));
+                    let gender = self.agreement_gender(organ);
+                    for word in traits {
+                        words.push(self.adjective(
+                            word,
+                            &Case::Abl,
+                            number,
+                            &gender,
+                            &Degree::Positive,
+                        ));
+                    }
+                }
+                TemplatePart::Differt(taxon, number) => {
+                    let taxon = self.complex_noun(taxon, &Case::Abl, number);
+                    words.push(Latin::preposition_form("ab", &taxon));
+                    words.push(taxon);
+                    words.push("differt".to_string());
+                }
+                TemplatePart::Stop => sentences.push(Latin::sentence(&words)),
+            }
+
+            after_trait = matches!(part, TemplatePart::Trait(_));
+            if let TemplatePart::Stop = part {
+                words.clear();
+            }
+        }
+        sentences.push(Latin::sentence(&words));
+
+        let sentences: Vec<String> = sentences.into_iter().filter(|s| !s.is_empty()).collect();
+        sentences.join(" ")
+    }
+
+    // One sentence per organ, "Folia ovata, glabra. Flores albi."
+    pub fn description(&self, organs: &[OrganDescription]) -> String {
+        let mut template = Vec::new();
+
+        for organ in organs {
+            template.push(TemplatePart::Organ(
+                organ.organ.clone(),
+                Case::Nom,
+                organ.number.clone(),
+            ));
+            for word in &organ.traits {
+                template.push(TemplatePart::Trait(word.clone()));
+            }
+            template.push(TemplatePart::Stop);
+        }
+
+        self.diagnosis(&template)
+    }
+
+    // "A. speciosa differt ab A. nana foliis maioribus et floribus albis". The organs are in the
+    // ablative plural with their traits in the given degree, the genus is abbreviated when
+    // both taxa share it
+    pub fn comparative_diagnosis(
+        &self,
+        taxon: &BinomialName,
+        compared: &BinomialName,
+        differences: &[(ComplexNoun, String, Degree)],
+    ) -> String {
+        let shared = taxon.genus.to_lowercase() == compared.genus.to_lowercase();
+        let compared = self.binomial_case(compared, &Case::Abl, shared);
+
+        let members: Vec<String> = differences
+            .iter()
+            .map(|(organ, word, degree)| {
+                let gender = self.agreement_gender(organ);
+                format!(
+                    "{} {}",
+                    self.complex_noun(organ, &Case::Abl, &Number::Plural),
+                    self.adjective(word, &Case::Abl, &Number::Plural, &gender, degree)
+                )
+            })
+            .collect();
+
+        Latin::sentence(&[
+            self.binomial_case(taxon, &Case::Nom, shared),
+            "differt".to_string(),
+            Latin::preposition_form("ab", &compared),
+            compared,
+            Latin::join_coordinated(&members, &Conjunction::Et),
+        ])
+    }
+
+    // Capitalized and ending in a full stop, empty when there are no words
+    pub fn sentence(words: &[String]) -> String {
+        let words: Vec<&str> = words
+            .iter()
+            .map(|w| w.as_str())
+            .filter(|w| !w.is_empty())
+            .collect();
+        let sentence = words.join(" ");
+
+        let mut chars = sentence.chars();
+        match chars.next() {
+            Some(first) => format!("{}{}.", first.to_uppercase(), chars.as_str()),
+            None => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    fn organ(head_noun: &str, adjective: &[&str]) -> ComplexNoun {
+        ComplexNoun {
+            head_noun: head_noun.to_string(),
+            adjective: adjective.iter().map(|a| a.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn template_diagnosis() {
+        let template = [
+            TemplatePart::Text("frutex".to_string()),
+            TemplatePart::Trait("erectus".to_string()),
+            TemplatePart::Stop,
+            TemplatePart::Organ(organ("folium", &[]), Case::Nom, Number::Plural),
+            TemplatePart::Trait("ovatus".to_string()),
+            TemplatePart::Trait("glaber".to_string()),
+            TemplatePart::Stop,
+            TemplatePart::AblativeAbsolute(
+                organ("folium", &[]),
+                Number::Plural,
+                vec!["deciduus".to_string()],
+            ),
+            TemplatePart::Differt(organ("species", &["affinis"]), Number::Plural),
+        ];
+
+        assert_eq!(
+            latin().diagnosis(&template),
+            "Frutex erectus. Folia ovata, glabra. Foliis deciduis a speciebus affinibus differt."
+        );
+    }
+
+    #[test]
+    fn organ_description() {
+        let organs = [
+            OrganDescription {
+                organ: organ("folium", &[]),
+                number: Number::Plural,
+                traits: vec!["ovatus".to_string(), "glaber".to_string()],
+            },
+            OrganDescription {
+                organ: organ("flos", &[]),
+                number: Number::Plural,
+                traits: vec!["albus".to_string()],
+            },
+        ];
+
+        assert_eq!(
+            latin().description(&organs),
+            "Folia ovata, glabra. Flores albi."
+        );
+    }
+
+    #[test]
+    fn comparative_diagnosis() {
+        let species = |epithet: &str| BinomialName {
+            genus: "Acacia".to_string(),
+            epithet: Epithet {
+                word: epithet.to_string(),
+                kind: EpithetKind::Adjectival,
+            },
+            infraspecific: None,
+        };
+        let differences = [
+            (
+                organ("folium", &[]),
+                "magnus".to_string(),
+                Degree::Comparative,
+            ),
+            (organ("flos", &[]), "albus".to_string(), Degree::Positive),
+        ];
+
+        assert_eq!(
+            latin().comparative_diagnosis(&species("speciosus"), &species("nanus"), &differences),
+            "A. speciosa differt ab A. nana foliis maioribus et floribus albis."
+        );
+    }
+}
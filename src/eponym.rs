@@ -0,0 +1,161 @@
+use crate::*;
+
+// Letters with diacritics and how they are written in scientific names, ICN Art. 60.7
+pub const DIACRITIC_TRANSCRIPTIONS: [(char, &str); 34] = [
+    ('ä', "ae"),
+    ('æ', "ae"),
+    ('ö', "oe"),
+    ('œ', "oe"),
+    ('ø', "oe"),
+    ('ü', "ue"),
+    ('å', "ao"),
+    ('á', "a"),
+    ('à', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('é', "e"),
+    ('è', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('í', "i"),
+    ('ì', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ó', "o"),
+    ('ò', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ú', "u"),
+    ('ù', "u"),
+    ('û', "u"),
+    ('ñ', "n"),
+    ('ç', "c"),
+    ('č', "c"),
+    ('š', "s"),
+    ('ž', "z"),
+    ('ý', "y"),
+    ('ÿ', "y"),
+    ('ß', "ss"),
+];
+
+// Particles left out of epithets when written in lower case, "de Candolle" gives "candollei"
+pub const NAME_PARTICLES: [&str; 8] = ["de", "d'", "du", "la", "le", "van", "von", "der"];
+
+impl Latin {
+    // "Müller" to "Mueller", "Añez" to "Anez"
+    pub fn transcribe_diacritics(word: &str) -> String {
+        let mut response = String::new();
+
+        for c in word.chars() {
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            match DIACRITIC_TRANSCRIPTIONS.iter().find(|(d, _)| *d == lower) {
+                Some((_, plain)) if c.is_uppercase() => {
+                    let mut plain = plain.chars();
+                    if let Some(first) = plain.next() {
+                        response.push_str(&first.to_uppercase().to_string());
+                        response.push_str(plain.as_str());
+                    }
+                }
+                Some((_, plain)) => response.push_str(plain),
+                None => response.push(c),
+            }
+        }
+
+        response
+    }
+
+    // Personal name as the stem of an epithet, lower case and without diacritics,
+    // particles, spaces or apostrophes. "Mc" and "M'" are written "mac"
+    pub fn eponym_stem(name: &str) -> String {
+        let words: Vec<&str> = name
+            .split_whitespace()
+            .filter(|w| !NAME_PARTICLES.contains(w))
+            .collect();
+
+        let mut stem = Latin::transcribe_diacritics(&words.concat()).to_lowercase();
+        stem.retain(|c| c.is_alphabetic() || c == '\'');
+
+        for prefix in ["mc", "m'"] {
+            if let Some(rest) = stem.strip_prefix(prefix) {
+                stem = format!("mac{rest}");
+            }
+        }
+        stem.retain(|c| c != '\'');
+
+        stem
+    }
+
+    // Substantive epithet in the genitive, ICN Rec. 60C.1(a) and (b). The number of honorees
+    // is the length of the slice, the feminine is used only when all of them are women.
+    // "hookeri", "brownii", "smithiae", "wilsoniorum", "trianae"
+    pub fn commemorative_epithet(name: &str, honorees: &[Gender]) -> String {
+        let stem = Latin::eponym_stem(name);
+        let plural = honorees.len() > 1;
+        let feminine = !honorees.is_empty() && honorees.iter().all(|g| g == &Gender::Feminine);
+
+        if stem.ends_with('a') {
+            return match plural {
+                false => format!("{stem}e"),
+                true => format!("{stem}rum"),
+            };
+        }
+
+        let ending = match (plural, feminine) {
+            (false, false) => "i",
+            (false, true) => "ae",
+            (true, false) => "orum",
+            (true, true) => "arum",
+        };
+
+        // Names already Latinized take the Latin genitive without their -us, "Linnaeus" gives
+        // "linnaei", or "linnaeorum" for more than one honoree
+        if stem.ends_with("aeus") || stem.ends_with("ius") {
+            let stem = &stem[..stem.len() - 2];
+            return match plural {
+                false => format!("{stem}i"),
+                true => format!("{stem}{ending}"),
+            };
+        }
+
+        if stem.ends_with(['a', 'e', 'i', 'o', 'u', 'y']) || stem.ends_with("er") {
+            format!("{stem}{ending}")
+        } else {
+            format!("{stem}i{ending}")
+        }
+    }
+
+    // Adjectival epithet as a masculine lemma for Latin::adjective, ICN Rec. 60C.1(c) and (d).
+    // "heyneanus", "balansanus", "hookerianus", "linnaeanus"
+    pub fn commemorative_adjective(name: &str) -> String {
+        let stem = Latin::eponym_stem(name);
+
+        if stem.ends_with("aeus") || stem.ends_with("ius") {
+            format!("{}anus", &stem[..stem.len() - 2])
+        } else if stem.ends_with('a') {
+            format!("{stem}nus")
+        } else if stem.ends_with(['e', 'i', 'o', 'u', 'y']) {
+            format!("{stem}anus")
+        } else {
+            format!("{stem}ianus")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latinized_names_lose_their_ending() {
+        let masculine = [Gender::Masculine];
+        let plural = [Gender::Masculine, Gender::Masculine];
+        assert_eq!(
+            Latin::commemorative_epithet("Linnaeus", &masculine),
+            "linnaei"
+        );
+        assert_eq!(
+            Latin::commemorative_epithet("Linnaeus", &plural),
+            "linnaeorum"
+        );
+    }
+}
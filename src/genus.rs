@@ -0,0 +1,191 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GenderRule {
+    // Curated list of genera whose gender rests on tradition, as the classical tree names
+    Tradition,
+    // ICN Art. 62, the termination that applied
+    IcnTermination(&'static str),
+    // Gender of the Latin noun in the dictionary
+    Dictionary,
+    // Guessed from a Latin ending, "-a", "-us" or "-um"
+    Ending,
+    // Nothing applied, the gender is Gender::Ambiguous without any listed
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GenusGender {
+    pub gender: Gender,
+    pub rule: GenderRule,
+}
+
+// Genera whose gender is fixed by tradition or conservation rather than by their ending.
+// Classical tree names in -us are feminine (ICN Art. 62.1)
+pub const GENUS_GENDER_EXCEPTIONS: [(&str, Gender); 39] = [
+    ("alnus", Gender::Feminine),
+    ("amygdalus", Gender::Feminine),
+    ("arbutus", Gender::Feminine),
+    ("buxus", Gender::Feminine),
+    ("carpinus", Gender::Feminine),
+    ("cedrus", Gender::Feminine),
+    ("cerasus", Gender::Feminine),
+    ("cornus", Gender::Feminine),
+    ("corylus", Gender::Feminine),
+    ("cupressus", Gender::Feminine),
+    ("cydonia", Gender::Feminine),
+    ("eucalyptus", Gender::Feminine),
+    ("fagus", Gender::Feminine),
+    ("ficus", Gender::Feminine),
+    ("fraxinus", Gender::Feminine),
+    ("juniperus", Gender::Feminine),
+    ("laurus", Gender::Feminine),
+    ("malus", Gender::Feminine),
+    ("morus", Gender::Feminine),
+    ("myrtus", Gender::Feminine),
+    ("ornus", Gender::Feminine),
+    ("pinus", Gender::Feminine),
+    ("platanus", Gender::Feminine),
+    ("populus", Gender::Feminine),
+    ("prunus", Gender::Feminine),
+    ("pyrus", Gender::Feminine),
+    ("quercus", Gender::Feminine),
+    ("sorbus", Gender::Feminine),
+    ("taxus", Gender::Feminine),
+    ("ulmus", Gender::Feminine),
+    ("agave", Gender::Feminine),
+    ("aloe", Gender::Feminine),
+    ("acer", Gender::Neuter),
+    ("cicer", Gender::Neuter),
+    ("papaver", Gender::Neuter),
+    ("piper", Gender::Neuter),
+    ("robur", Gender::Neuter),
+    ("siler", Gender::Neuter),
+    ("zingiber", Gender::Neuter),
+];
+
+// Latin endings that tell the gender of a genus not in the dictionary
+pub const GENUS_GENDER_ENDINGS: [(&str, Gender); 3] = [
+    ("a", Gender::Feminine),
+    ("us", Gender::Masculine),
+    ("um", Gender::Neuter),
+];
+
+// Greek compounds with an assigned gender, ICN Art. 62.2 and 62.4
+pub const ICN_GENDER_TERMINATIONS: [(&str, Gender); 20] = [
+    ("codon", Gender::Masculine),
+    ("myces", Gender::Masculine),
+    ("odon", Gender::Masculine),
+    ("panax", Gender::Masculine),
+    ("pogon", Gender::Masculine),
+    ("stemon", Gender::Masculine),
+    ("achne", Gender::Feminine),
+    ("chlamys", Gender::Feminine),
+    ("daphne", Gender::Feminine),
+    ("glochin", Gender::Feminine),
+    ("mecon", Gender::Feminine),
+    ("osma", Gender::Feminine),
+    ("anthes", Gender::Feminine),
+    ("oides", Gender::Feminine),
+    ("odes", Gender::Feminine),
+    ("ceras", Gender::Neuter),
+    ("dendron", Gender::Neuter),
+    ("nema", Gender::Neuter),
+    ("stigma", Gender::Neuter),
+    ("stoma", Gender::Neuter),
+];
+
+impl Latin {
+    // Gender of a generic name and the rule it was decided by. Tradition comes first,
+    // then the ICN terminations, then the noun dictionary and lastly the ending. A name that
+    // none of them fit, "Ginkgo", is of unknown gender
+    pub fn genus_gender(&self, genus: &str) -> GenusGender {
+        let genus = genus.to_lowercase();
+
+        if let Some((_, gender)) = GENUS_GENDER_EXCEPTIONS.iter().find(|(g, _)| *g == genus) {
+            return GenusGender {
+                gender: gender.clone(),
+                rule: GenderRule::Tradition,
+            };
+        }
+
+        // The longest termination is the compound element, "Platycodon" ends in "-codon" and
+        // not in "-odon", and "Codon" on its own is no compound at all
+        let termination = ICN_GENDER_TERMINATIONS
+            .iter()
+            .filter(|(termination, _)| genus.ends_with(termination))
+            .max_by_key(|(termination, _)| termination.len());
+        if let Some((termination, gender)) = termination {
+            if genus.len() > termination.len() {
+                return GenusGender {
+                    gender: gender.clone(),
+                    rule: GenderRule::IcnTermination(termination),
+                };
+            }
+        }
+
+        if let Some(record) = self.noun_map.get(&genus) {
+            return GenusGender {
+                gender: record.gender.clone(),
+                rule: GenderRule::Dictionary,
+            };
+        }
+
+        match GENUS_GENDER_ENDINGS
+            .iter()
+            .find(|(ending, _)| genus.ends_with(ending))
+        {
+            Some((_, gender)) => GenusGender {
+                gender: gender.clone(),
+                rule: GenderRule::Ending,
+            },
+            None => GenusGender {
+                gender: Gender::Ambiguous(&[]),
+                rule: GenderRule::Unknown,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    #[test]
+    fn tradition_comes_first() {
+        let quercus = latin().genus_gender("Quercus");
+        assert_eq!(quercus.gender, Gender::Feminine);
+        assert_eq!(quercus.rule, GenderRule::Tradition);
+
+        assert_eq!(latin().genus_gender("Acer").gender, Gender::Neuter);
+    }
+
+    #[test]
+    fn icn_terminations() {
+        let rhododendron = latin().genus_gender("Rhododendron");
+        assert_eq!(rhododendron.gender, Gender::Neuter);
+        assert_eq!(rhododendron.rule, GenderRule::IcnTermination("dendron"));
+
+        let platycodon = latin().genus_gender("Platycodon");
+        assert_eq!(platycodon.rule, GenderRule::IcnTermination("codon"));
+
+        assert_ne!(
+            latin().genus_gender("Codon").rule,
+            GenderRule::IcnTermination("odon")
+        );
+    }
+
+    #[test]
+    fn unknown_genera() {
+        for genus in ["Ginkgo", "Xyz"] {
+            let unknown = latin().genus_gender(genus);
+            assert_eq!(unknown.gender, Gender::Ambiguous(&[]));
+            assert_eq!(unknown.rule, GenderRule::Unknown);
+        }
+
+        let zelkova = latin().genus_gender("Zelkova");
+        assert_eq!(zelkova.gender, Gender::Feminine);
+        assert_eq!(zelkova.rule, GenderRule::Ending);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GeographicSuffix {
+    // "canadensis", "chinensis", the usual suffix for a locality
+    Ensis,
+    // "africanus", "virginianus"
+    Anus,
+    // "italicus", "japonicus"
+    Icus,
+    // "alpinus", "montanus" in the sense of a kind of place
+    Inus,
+}
+
+// Places with an established epithet: modern name, Latin name and epithet as a masculine lemma
+pub const PLACE_NAMES: [(&str, &str, &str); 60] = [
+    ("africa", "africa", "africanus"),
+    ("alps", "alpes", "alpinus"),
+    ("america", "america", "americanus"),
+    ("arabia", "arabia", "arabicus"),
+    ("armenia", "armenia", "armeniacus"),
+    ("atlas", "atlas", "atlanticus"),
+    ("austria", "austria", "austriacus"),
+    ("azores", "azores", "azoricus"),
+    ("bohemia", "bohemia", "bohemicus"),
+    ("borneo", "borneo", "borneensis"),
+    ("brazil", "brasilia", "brasiliensis"),
+    ("britain", "britannia", "britannicus"),
+    ("california", "california", "californicus"),
+    ("canada", "canada", "canadensis"),
+    ("canary islands", "canariae", "canariensis"),
+    ("cape of good hope", "caput bonae spei", "capensis"),
+    ("carolina", "carolina", "carolinianus"),
+    ("caucasus", "caucasus", "caucasicus"),
+    ("ceylon", "zeylania", "zeylanicus"),
+    ("chile", "chilia", "chilensis"),
+    ("china", "sina", "sinensis"),
+    ("crete", "creta", "creticus"),
+    ("cuba", "cuba", "cubensis"),
+    ("cyprus", "cyprus", "cyprius"),
+    ("egypt", "aegyptus", "aegyptiacus"),
+    ("ethiopia", "aethiopia", "aethiopicus"),
+    ("europe", "europa", "europaeus"),
+    ("florida", "florida", "floridanus"),
+    ("france", "gallia", "gallicus"),
+    ("germany", "germania", "germanicus"),
+    ("greece", "graecia", "graecus"),
+    ("guinea", "guinea", "guineensis"),
+    ("himalaya", "himalaya", "himalaicus"),
+    ("hungary", "hungaria", "hungaricus"),
+    ("india", "india", "indicus"),
+    ("ireland", "hibernia", "hibernicus"),
+    ("italy", "italia", "italicus"),
+    ("jamaica", "jamaica", "jamaicensis"),
+    ("japan", "japonia", "japonicus"),
+    ("java", "java", "javanicus"),
+    ("korea", "corea", "coreanus"),
+    ("madagascar", "madagascaria", "madagascariensis"),
+    ("mexico", "mexicum", "mexicanus"),
+    ("morocco", "marocco", "maroccanus"),
+    ("nepal", "nepalia", "nepalensis"),
+    ("norway", "norvegia", "norvegicus"),
+    ("persia", "persia", "persicus"),
+    ("peru", "peruvia", "peruvianus"),
+    ("philippines", "philippinae", "philippinensis"),
+    ("portugal", "lusitania", "lusitanicus"),
+    ("pyrenees", "pyrenaei", "pyrenaicus"),
+    ("russia", "rossia", "rossicus"),
+    ("scotland", "scotia", "scoticus"),
+    ("siberia", "sibiria", "sibiricus"),
+    ("spain", "hispania", "hispanicus"),
+    ("sweden", "suecia", "suecicus"),
+    ("taiwan", "formosa", "formosanus"),
+    ("texas", "texia", "texanus"),
+    ("tibet", "tibetia", "tibeticus"),
+    ("virginia", "virginia", "virginianus"),
+];
+
+impl Latin {
+    // Epithet of a place as a masculine lemma for Latin::adjective, from the place-name
+    // dictionary when the place is known by its modern or Latin name
+    pub fn place_epithet(place: &str) -> Option<String> {
+        let place = Latin::transcribe_diacritics(place).to_lowercase();
+
+        PLACE_NAMES
+            .iter()
+            .find(|(modern, latin, _)| *modern == place || *latin == place)
+            .map(|(_, _, epithet)| epithet.to_string())
+    }
+
+    // Forms an epithet with the given suffix, "Yunnan" gives "yunnanensis". A final "-ia" or a
+    // single final a, e, i or o of the place name is dropped, "-anus" keeps the i of "-ia" and
+    // "-ensis" loses only its a, "virginiensis"
+    pub fn geographic_epithet(place: &str, suffix: &GeographicSuffix) -> String {
+        let mut stem = Latin::transcribe_diacritics(place).to_lowercase();
+        stem.retain(|c| c.is_alphabetic());
+
+        let mut ia = false;
+        if stem.ends_with("ia") && suffix != &GeographicSuffix::Ensis {
+            stem.truncate(stem.len() - 2);
+            ia = true;
+        } else if stem.ends_with(['a', 'e', 'i', 'o']) {
+            stem.pop();
+        }
+
+        let suffix = match suffix {
+            GeographicSuffix::Ensis => "ensis",
+            GeographicSuffix::Anus if ia => "ianus",
+            GeographicSuffix::Anus => "anus",
+            GeographicSuffix::Icus => "icus",
+            GeographicSuffix::Inus => "inus",
+        };
+
+        format!("{stem}{suffix}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geographic_epithets() {
+        let epithet = Latin::geographic_epithet;
+        assert_eq!(
+            epithet("Virginia", &GeographicSuffix::Ensis),
+            "virginiensis"
+        );
+        assert_eq!(epithet("Bolivia", &GeographicSuffix::Ensis), "boliviensis");
+        assert_eq!(epithet("China", &GeographicSuffix::Ensis), "chinensis");
+        assert_eq!(epithet("Yunnan", &GeographicSuffix::Ensis), "yunnanensis");
+        assert_eq!(epithet("Italia", &GeographicSuffix::Icus), "italicus");
+        assert_eq!(epithet("Virginia", &GeographicSuffix::Anus), "virginianus");
+        assert_eq!(epithet("Peru", &GeographicSuffix::Anus), "peruanus");
+    }
+}
@@ -0,0 +1,154 @@
+use crate::*;
+use serde::de::{value::StrDeserializer, IntoDeserializer};
+
+pub type GlossaryMap = HashMap<String, GlossaryRecord>;
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PartOfSpeech {
+    Noun,
+    Adjective,
+    Adverb,
+    Preposition,
+    Verb,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct GlossaryRecord {
+    pub latin: String,
+    // Glosses separated by ";", "vein;nerve"
+    pub english: String,
+    pub part_of_speech: PartOfSpeech,
+    // Only for nouns
+    #[serde(deserialize_with = "deserialize_optional_gender")]
+    pub gender: Option<Gender>,
+    // How the term is used in descriptions
+    pub usage: String,
+}
+
+fn deserialize_optional_gender<'de, D>(deserializer: D) -> Result<Option<Gender>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: String = String::deserialize(deserializer)?;
+    match s.as_str() {
+        "" | "-" => Ok(None),
+        _ => {
+            let s: StrDeserializer<D::Error> = s.as_str().into_deserializer();
+            deserialize_gender(s).map(Some)
+        }
+    }
+}
+
+impl GlossaryRecord {
+    pub fn glosses(&self) -> impl Iterator<Item = &str> {
+        self.english.split(';').map(|gloss| gloss.trim())
+    }
+}
+
+impl Latin {
+    pub fn load_glossary_from_csv(path: String) -> GlossaryMap {
+        let mut glossary = HashMap::new();
+        let mut rdr = csv::Reader::from_path(path.as_str()).unwrap();
+        for result in rdr.deserialize() {
+            let record: GlossaryRecord = result.unwrap();
+            glossary.insert(record.latin.clone(), record);
+        }
+        glossary
+    }
+
+    // The glossary is optional, "glossary.csv" in the github repo
+    pub fn load_glossary(&mut self, path: String) {
+        self.glossary = Latin::load_glossary_from_csv(path);
+    }
+
+    // Entries with the given English gloss, sorted by their Latin term
+    pub fn glossary_lookup(&self, english: &str) -> Vec<&GlossaryRecord> {
+        let english = english.trim().to_lowercase();
+
+        let mut records: Vec<&GlossaryRecord> = self
+            .glossary
+            .values()
+            .filter(|record| record.glosses().any(|gloss| gloss == english))
+            .collect();
+        records.sort_by(|a, b| a.latin.cmp(&b.latin));
+
+        records
+    }
+
+    fn glossary_term(
+        &self,
+        english: &str,
+        part_of_speech: PartOfSpeech,
+    ) -> Option<&GlossaryRecord> {
+        self.glossary_lookup(english)
+            .into_iter()
+            .find(|record| record.part_of_speech == part_of_speech)
+    }
+
+    // "leaf" to "foliis" in the ablative plural
+    pub fn translate_organ(&self, english: &str, case: &Case, number: &Number) -> Option<String> {
+        let record = self.glossary_term(english, PartOfSpeech::Noun)?;
+        Some(self.noun(&record.latin, case, number).0)
+    }
+
+    // "hairless" to "glabris" for "folium" in the ablative plural, agreeing with the organ
+    pub fn translate_trait(
+        &self,
+        english: &str,
+        organ: &ComplexNoun,
+        case: &Case,
+        number: &Number,
+    ) -> Option<String> {
+        let record = self.glossary_term(english, PartOfSpeech::Adjective)?;
+        let gender = self.agreement_gender(organ);
+
+        Some(self.adjective(&record.latin, case, number, &gender, &Degree::Positive))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    #[test]
+    fn glossary_loads_from_csv() {
+        let glossary = Latin::load_glossary_from_csv("glossary.csv".to_string());
+        let folium = glossary.get("folium").unwrap();
+        assert_eq!(folium.part_of_speech, PartOfSpeech::Noun);
+        assert_eq!(folium.gender, Some(Gender::Neuter));
+    }
+
+    #[test]
+    fn lookup_by_english() {
+        let hairy: Vec<&str> = latin()
+            .glossary_lookup("Hairy")
+            .iter()
+            .map(|record| record.latin.as_str())
+            .collect();
+        assert_eq!(hairy, vec!["hirsutus"]);
+    }
+
+    #[test]
+    fn translations_agree_with_the_organ() {
+        let latin = latin();
+        assert_eq!(
+            latin.translate_organ("leaf", &Case::Abl, &Number::Plural),
+            Some("foliis".to_string())
+        );
+
+        let folium = ComplexNoun {
+            head_noun: "folium".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            latin.translate_trait("hairy", &folium, &Case::Nom, &Number::Plural),
+            Some("hirsuta".to_string())
+        );
+        assert_eq!(
+            latin.translate_trait("sparkly", &folium, &Case::Nom, &Number::Plural),
+            None
+        );
+    }
+}
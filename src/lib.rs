@@ -1,7 +1,39 @@
 use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
+#[allow(unused_imports)]
 use std::error::Error;
 
+mod binomial;
+mod clause;
+mod color;
+mod compound;
+mod coordination;
+mod diagnosis;
+mod eponym;
+mod genus;
+mod geography;
+mod glossary;
+mod measurement;
+mod numerals;
+mod preposition;
+mod pronoun;
+mod validation;
+pub use binomial::*;
+pub use clause::*;
+pub use color::*;
+pub use compound::*;
+pub use coordination::*;
+pub use diagnosis::*;
+pub use eponym::*;
+pub use genus::*;
+pub use geography::*;
+pub use glossary::*;
+pub use measurement::*;
+pub use numerals::*;
+pub use preposition::*;
+pub use pronoun::*;
+pub use validation::*;
+
 pub type NounMap = HashMap<String, NounRecord>;
 pub type AdjectiveMap = HashMap<String, AdjectiveRecord>;
 pub type VerbMap = HashMap<String, VerbRecord>;
@@ -12,22 +44,75 @@ pub struct Latin {
     pub noun_map: NounMap,
     pub adj_map: AdjectiveMap,
     pub verb_map: VerbMap,
+    pub glossary: GlossaryMap,
 }
 
+#[derive(Debug, Clone)]
 pub struct ComplexNoun {
     //   pub case: Case,
     //  pub number: Number,
     pub head_noun: String,
     pub adjective: Vec<String>,
     pub adposition_noun: Vec<String>,
+    // Gender the adjectives agree with, defaults to the gender of the head noun
+    pub agreement_gender: Option<Gender>,
+    // Gender for a single adjective, overriding agreement_gender
+    pub adjective_gender: HashMap<String, Gender>,
+    // Dependent genitive with its own number and modifiers, "margine foliorum serrato"
+    pub genitive: Option<Box<(ComplexNoun, Number)>>,
+    pub word_order: WordOrder,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub enum WordOrder {
+    // Head noun, appositions, genitive, adjectives, as usual in botanical Latin
+    #[default]
+    Postnominal,
+    // Adjectives before the head noun
+    Prenominal,
+    // Numerals, demonstratives and adjectives of quantity before the noun, the rest after
+    Classical,
+    // Parts are rendered in the given order, parts left out are not rendered
+    Explicit(Vec<PhraseSlot>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PhraseSlot {
+    Head,
+    // Index into adposition_noun
+    Apposition(usize),
+    Genitive,
+    // Index into adjective
+    Adjective(usize),
 }
 
+pub const PREPOSED_ADJECTIVES: [&str; 14] = [
+    "omnis",
+    "multus",
+    "paucus",
+    "magnus",
+    "parvus",
+    "totus",
+    "nullus",
+    "ullus",
+    "solus",
+    "alius",
+    "alter",
+    "ceterus",
+    "plerusque",
+    "nonnullus",
+];
+
 impl Default for ComplexNoun {
     fn default() -> Self {
         Self {
             head_noun: "exemplum".into(),
             adposition_noun: Vec::new(),
             adjective: Vec::new(),
+            agreement_gender: None,
+            adjective_gender: HashMap::new(),
+            genitive: None,
+            word_order: WordOrder::Postnominal,
         }
     }
 }
@@ -52,6 +137,9 @@ pub struct NounRecord {
 
     #[serde(deserialize_with = "deserialize_gender")]
     pub gender: Gender,
+
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    pub indeclinable: bool,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -79,6 +167,7 @@ pub enum Tense {
     FuturePerfect,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Gender {
     fn default() -> Gender {
         Gender::Masculine
@@ -163,6 +252,38 @@ pub struct AdjectiveRecord {
     pub dat_pl_neut: String,
     pub acc_pl_neut: String,
     pub abl_pl_neut: String,
+
+    // Optional columns, derived from the other cases when missing
+    #[serde(default)]
+    pub voc_sg_masc: String,
+    #[serde(default)]
+    pub voc_sg_fem: String,
+    #[serde(default)]
+    pub voc_sg_neut: String,
+    #[serde(default)]
+    pub voc_pl_masc: String,
+    #[serde(default)]
+    pub voc_pl_fem: String,
+    #[serde(default)]
+    pub voc_pl_neut: String,
+    #[serde(default)]
+    pub loc_sg_masc: String,
+    #[serde(default)]
+    pub loc_sg_fem: String,
+    #[serde(default)]
+    pub loc_sg_neut: String,
+    #[serde(default)]
+    pub loc_pl_masc: String,
+    #[serde(default)]
+    pub loc_pl_fem: String,
+    #[serde(default)]
+    pub loc_pl_neut: String,
+
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    pub indeclinable: bool,
+    // Genitive singular in -ius and dative in -i, the UNUS NAUTA group
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    pub pronominal: bool,
 }
 
 fn deserialize_gender<'de, D>(deserializer: D) -> Result<Gender, D::Error>
@@ -174,10 +295,22 @@ where
         "m" => Ok(Gender::Masculine),
         "f" => Ok(Gender::Feminine),
         "n" => Ok(Gender::Neuter),
+        "mf" | "fm" | "c" => Ok(Gender::Common),
+        "mn" => Ok(Gender::Ambiguous(&[Gender::Masculine, Gender::Neuter])),
+        "nm" => Ok(Gender::Ambiguous(&[Gender::Neuter, Gender::Masculine])),
+        "fn" => Ok(Gender::Ambiguous(&[Gender::Feminine, Gender::Neuter])),
+        "nf" => Ok(Gender::Ambiguous(&[Gender::Neuter, Gender::Feminine])),
+        "mfn" => Ok(Gender::Ambiguous(&[
+            Gender::Masculine,
+            Gender::Feminine,
+            Gender::Neuter,
+        ])),
+        "a" => Ok(Gender::Ambiguous(&[])),
         _ => Err(serde::de::Error::custom("unknown gender")),
     }
 }
 
+#[allow(dead_code)]
 fn deserialize_pluralia<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: Deserializer<'de>,
@@ -191,11 +324,57 @@ where
     }
 }
 
+fn deserialize_flag<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: String = String::deserialize(deserializer)?;
+    match s.as_str() {
+        "" | "fa" | "0" => Ok(false),
+        "tr" | "1" => Ok(true),
+        _ => Err(serde::de::Error::custom("unknown flag")),
+    }
+}
+
+// Words that are never declined, registered by Latin::new on top of the csv dictionaries
+pub const INDECLINABLE_NOUNS: [(&str, Gender); 7] = [
+    ("nihil", Gender::Neuter),
+    ("nil", Gender::Neuter),
+    ("fas", Gender::Neuter),
+    ("nefas", Gender::Neuter),
+    ("instar", Gender::Neuter),
+    ("pondo", Gender::Neuter),
+    ("gummi", Gender::Neuter),
+];
+
+pub const INDECLINABLE_ADJECTIVES: [&str; 15] = [
+    "quattuor", "quinque", "sex", "septem", "octo", "novem", "decem", "viginti", "centum", "mille",
+    "tot", "quot", "aliquot", "nequam", "frugi",
+];
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Gender {
     Masculine,
     Feminine,
     Neuter,
+    // Masculine or feminine depending on the referent, "civis", "dies"
+    Common,
+    // Attested with differing genders, the usual one first, or disputed as with some genera
+    // when none is listed
+    Ambiguous(&'static [Gender]),
+}
+
+impl Gender {
+    // The gender adjectives take when the caller does not choose one. Common genders fall back
+    // to the masculine, ambiguous ones to the first gender listed
+    pub fn agreement(&self) -> Gender {
+        match self {
+            Gender::Feminine => Gender::Feminine,
+            Gender::Neuter => Gender::Neuter,
+            Gender::Ambiguous([first, ..]) => first.agreement(),
+            _ => Gender::Masculine,
+        }
+    }
 }
 #[derive(Debug, PartialEq, Clone)]
 pub enum Case {
@@ -416,6 +595,52 @@ pub const OR_ADJ_NEUTER_ENDINGS: CaseEndings = CaseEndings {
     abl_pl: "oribus",
 };
 
+pub const COMPARATIVE_NEUTER_ENDINGS: CaseEndings = CaseEndings {
+    gender: Gender::Neuter,
+    nom_sg: "us",
+    acc_sg: "us",
+    gen_sg: "oris",
+    dat_sg: "ori",
+    abl_sg: "ore",
+
+    nom_pl: "ora",
+    acc_pl: "ora",
+    gen_pl: "orum",
+    dat_pl: "oribus",
+    abl_pl: "oribus",
+};
+
+// "plus" has no masculine or feminine singular, the neuter stands in for them
+pub const PLUS_ENDINGS: CaseEndings = CaseEndings {
+    gender: Gender::Masculine,
+    nom_sg: "us",
+    acc_sg: "us",
+    gen_sg: "uris",
+    dat_sg: "uri",
+    abl_sg: "ure",
+
+    nom_pl: "ures",
+    acc_pl: "ures",
+    gen_pl: "urium",
+    dat_pl: "uribus",
+    abl_pl: "uribus",
+};
+
+pub const PLUS_NEUTER_ENDINGS: CaseEndings = CaseEndings {
+    gender: Gender::Neuter,
+    nom_sg: "us",
+    acc_sg: "us",
+    gen_sg: "uris",
+    dat_sg: "uri",
+    abl_sg: "ure",
+
+    nom_pl: "ura",
+    acc_pl: "ura",
+    gen_pl: "urium",
+    dat_pl: "uribus",
+    abl_pl: "uribus",
+};
+
 pub const OS_DECLENSION_ENDINGS: CaseEndings = CaseEndings {
     gender: Gender::Masculine,
     nom_sg: "os",
@@ -831,6 +1056,17 @@ pub const MA_DECLENSION_ENDINGS: CaseEndings = CaseEndings {
     abl_pl: "matibus",
 };
 
+// How an adjective agrees with several coordinated nouns
+#[derive(Debug, PartialEq, Clone)]
+pub enum AgreementRule {
+    // Attributive use, gender and number of the nearest noun, "folia et caules glabri"
+    Nearest,
+    // Predicate of persons, masculine plural unless all nouns are feminine
+    Persons,
+    // Predicate of things, neuter plural unless all nouns share a gender
+    Things,
+}
+
 // have a possesive func, but reflexive person?
 #[derive(Debug, PartialEq, Clone)]
 pub enum Number {
@@ -841,6 +1077,74 @@ pub enum Number {
 pub type Noun = (String, Gender);
 pub type Adjective = String;
 
+#[derive(Debug, PartialEq, Clone, Default)]
+pub enum Degree {
+    #[default]
+    Positive,
+    Comparative,
+    Superlative,
+}
+
+// positive, comparative, superlative
+pub const IRREGULAR_COMPARISON: [(&str, &str, &str); 9] = [
+    ("bonus", "melior", "optimus"),
+    ("malus", "peior", "pessimus"),
+    ("magnus", "maior", "maximus"),
+    ("parvus", "minor", "minimus"),
+    ("multus", "plus", "plurimus"),
+    ("superus", "superior", "supremus"),
+    ("inferus", "inferior", "infimus"),
+    ("exterus", "exterior", "extremus"),
+    ("posterus", "posterior", "postremus"),
+];
+
+// adjective, positive, comparative and superlative adverb
+pub const IRREGULAR_ADVERBS: [(&str, &str, &str, &str); 6] = [
+    ("bonus", "bene", "melius", "optime"),
+    ("malus", "male", "peius", "pessime"),
+    ("magnus", "magnopere", "magis", "maxime"),
+    ("parvus", "paulum", "minus", "minime"),
+    ("multus", "multum", "plus", "plurimum"),
+    ("facilis", "facile", "facilius", "facillime"),
+];
+
+// Positive adverbs in -im, frequent in descriptions, "sparsim", "gradatim"
+pub const IM_ADVERBS: [(&str, &str); 6] = [
+    ("sparsus", "sparsim"),
+    ("gradatus", "gradatim"),
+    ("separatus", "separatim"),
+    ("confertus", "confertim"),
+    ("alternatus", "alternatim"),
+    ("privatus", "privatim"),
+];
+
+// Third declension adjectives in -er, adverb in -iter rather than -e
+pub const THIRD_DECLENSION_ER: [&str; 6] =
+    ["acer", "alacer", "celer", "volucer", "saluber", "celeber"];
+
+// The six adjectives in -lis with a superlative in -llimus
+pub const LIS_SUPERLATIVES: [&str; 6] = [
+    "facilis",
+    "difficilis",
+    "similis",
+    "dissimilis",
+    "gracilis",
+    "humilis",
+];
+
+// Adjectives in -er that keep the e throughout, "liber" -> "liberior"
+pub const ER_KEEPS_E: [&str; 8] = [
+    "liber", "asper", "tener", "miser", "lacer", "prosper", "gibber", "celer",
+];
+
+// Adverbs of periphrastic comparison, "magis idoneus", "maxime idoneus"
+pub const PERIPHRASTIC_ADVERBS: [&str; 2] = ["magis", "maxime"];
+
+// UNUS NAUTA: genitive singular in -ius and dative singular in -i for every gender
+pub const PRONOMINAL_ADJECTIVES: [&str; 9] = [
+    "unus", "nullus", "ullus", "solus", "neuter", "alius", "uter", "totus", "alter",
+];
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Person {
     First,
@@ -876,6 +1180,29 @@ impl Latin {
     }
 
     pub fn guess_adjective(word: &str, case: &Case, number: &Number, gender: &Gender) -> Adjective {
+        match case {
+            Case::Voc => {
+                return Latin::vocative_from_forms(
+                    &Latin::guess_adjective(word, &Case::Nom, number, gender),
+                    &Latin::guess_adjective(word, &Case::Gen, number, gender),
+                    number,
+                    gender,
+                )
+            }
+            Case::Loc => {
+                return Latin::locative_from_forms(
+                    &Latin::guess_adjective(word, &Case::Gen, number, gender),
+                    &Latin::guess_adjective(word, &Case::Abl, number, gender),
+                    number,
+                )
+            }
+            _ => {}
+        }
+
+        if PRONOMINAL_ADJECTIVES.contains(&word) {
+            return Latin::guess_pronominal_adjective(word, case, number, gender);
+        }
+
         let mut word_stem = word.to_string();
         word_stem.truncate(word_stem.len() - 2);
 
@@ -931,15 +1258,32 @@ impl Latin {
             neut_ends = &S_ADJ_NEUTER_ENDINGS;
         }
 
-        let ending = match gender {
+        let ending = match gender.agreement() {
             Gender::Feminine => fem_ends.ending(case, number),
-            Gender::Masculine => masc_ends.ending(case, number),
             Gender::Neuter => neut_ends.ending(case, number),
+            _ => masc_ends.ending(case, number),
         };
 
         format!("{word_stem}{ending}")
     }
 
+    // Gender adjectives agree with: the one given for the phrase, else the gender of the head
+    // noun in the glossary and lastly in the noun dictionary
+    pub fn agreement_gender(&self, complex_nomen: &ComplexNoun) -> Gender {
+        complex_nomen
+            .agreement_gender
+            .clone()
+            .or_else(|| {
+                self.glossary
+                    .get(&complex_nomen.head_noun)
+                    .and_then(|record| record.gender.clone())
+            })
+            .unwrap_or_else(|| {
+                self.noun(&complex_nomen.head_noun, &Case::Nom, &Number::Singular)
+                    .1
+            })
+    }
+
     pub fn complex_noun(
         &self,
         complex_nomen: &ComplexNoun,
@@ -947,32 +1291,159 @@ impl Latin {
         number: &Number,
     ) -> String {
         let noun = self.noun(&complex_nomen.head_noun, case, number);
+        let agreement = complex_nomen.agreement_gender.clone().unwrap_or(noun.1);
+
+        let adjectives: Vec<String> = complex_nomen
+            .adjective
+            .iter()
+            .map(|adj| {
+                let gender = complex_nomen
+                    .adjective_gender
+                    .get(adj)
+                    .unwrap_or(&agreement);
+                self.modifier(adj, case, number, gender)
+            })
+            .collect();
+
+        let mut response = String::new();
+
+        for slot in Latin::phrase_slots(complex_nomen) {
+            let word = match slot {
+                PhraseSlot::Head => noun.0.clone(),
+                PhraseSlot::Apposition(i) => match complex_nomen.adposition_noun.get(i) {
+                    Some(adpos) => self.noun(adpos, case, number).0,
+                    None => String::new(),
+                },
+                PhraseSlot::Genitive => match &complex_nomen.genitive {
+                    Some(genitive) => {
+                        let (dependent, dependent_number) = genitive.as_ref();
+                        self.complex_noun(dependent, &Case::Gen, dependent_number)
+                    }
+                    None => String::new(),
+                },
+                PhraseSlot::Adjective(i) => adjectives.get(i).cloned().unwrap_or_default(),
+            };
 
-        let mut response = noun.0;
-
-        for adpos in &complex_nomen.adposition_noun {
-            let adposik = self.noun(adpos, case, number);
-            if adposik.0 != "" {
-                response = format!("{} {}", response, adposik.0);
+            if !word.is_empty() {
+                response = match response.is_empty() {
+                    true => word,
+                    false => format!("{} {}", response, word),
+                };
             }
         }
 
-        for adj in &complex_nomen.adjective {
-            let adjik = self.adjective(adj, case, number, &noun.1);
-            if adjik != "" {
-                response = format!("{} {}", response, adjik);
+        response
+    }
+
+    // Demonstratives and the declinable cardinals may stand among the adjectives
+    fn modifier(&self, word: &str, case: &Case, number: &Number, gender: &Gender) -> String {
+        if let Some((_, kind)) = DEMONSTRATIVES.iter().find(|(lemma, _)| *lemma == word) {
+            return Latin::pronoun(kind, case, number, gender, &Person::Third);
+        }
+
+        match CARDINAL_UNITS.iter().position(|lemma| *lemma == word) {
+            Some(n @ 2..=3) => Latin::cardinal(n as u32, case, gender).unwrap_or_default(),
+            _ => self.adjective(word, case, number, gender, &Degree::Positive),
+        }
+    }
+
+    // The order complex_noun renders the parts of a phrase in
+    pub fn phrase_slots(complex_nomen: &ComplexNoun) -> Vec<PhraseSlot> {
+        let head: Vec<PhraseSlot> = std::iter::once(PhraseSlot::Head)
+            .chain((0..complex_nomen.adposition_noun.len()).map(PhraseSlot::Apposition))
+            .chain(complex_nomen.genitive.iter().map(|_| PhraseSlot::Genitive))
+            .collect();
+        let adjectives = (0..complex_nomen.adjective.len()).map(PhraseSlot::Adjective);
+
+        match &complex_nomen.word_order {
+            WordOrder::Postnominal => head.into_iter().chain(adjectives).collect(),
+            WordOrder::Prenominal => adjectives.chain(head).collect(),
+            WordOrder::Classical => {
+                let (before, after): (Vec<PhraseSlot>, Vec<PhraseSlot>) =
+                    adjectives.partition(|slot| match slot {
+                        PhraseSlot::Adjective(i) => {
+                            Latin::precedes_noun(&complex_nomen.adjective[*i])
+                        }
+                        _ => false,
+                    });
+                before.into_iter().chain(head).chain(after).collect()
             }
+            WordOrder::Explicit(slots) => slots.clone(),
         }
+    }
 
-        response
+    // Numerals, demonstratives and adjectives of quantity come before the noun in classical prose
+    pub fn precedes_noun(word: &str) -> bool {
+        let numeral = [
+            &CARDINAL_UNITS[1..],
+            &ORDINAL_UNITS[1..],
+            &DISTRIBUTIVE_UNITS[1..],
+        ]
+        .iter()
+        .any(|lemmas| lemmas.contains(&word));
+
+        numeral
+            || DEMONSTRATIVES.iter().any(|(lemma, _)| *lemma == word)
+            || PREPOSED_ADJECTIVES.contains(&word)
     }
 
-    pub fn noun(&self, word: &str, case: &Case, number: &Number) -> Noun {
-        let defik = NounRecord::default();
+    // Gender and number an adjective takes for coordinated nouns, given in order so the
+    // last one is the nearest to the adjective
+    pub fn coordinated_agreement(
+        &self,
+        nouns: &[(&str, Number)],
+        rule: &AgreementRule,
+    ) -> (Gender, Number) {
+        let genders: Vec<Gender> = nouns
+            .iter()
+            .map(|(noun, _)| self.noun(noun, &Case::Nom, &Number::Singular).1)
+            .collect();
+
+        let (nearest, nearest_number) = match (genders.last(), nouns.last()) {
+            (Some(gender), Some((_, number))) => (gender.agreement(), number.clone()),
+            _ => return (Gender::Masculine, Number::Plural),
+        };
 
+        if nouns.len() == 1 || rule == &AgreementRule::Nearest {
+            return (nearest, nearest_number);
+        }
+
+        let gender = match rule {
+            AgreementRule::Persons if genders.iter().all(|g| g == &Gender::Feminine) => {
+                Gender::Feminine
+            }
+            AgreementRule::Persons => Gender::Masculine,
+            _ if genders.iter().all(|g| g.agreement() == nearest) => nearest,
+            _ => Gender::Neuter,
+        };
+
+        (gender, Number::Plural)
+    }
+
+    pub fn coordinated_adjective(
+        &self,
+        adjective: &str,
+        nouns: &[(&str, Number)],
+        case: &Case,
+        rule: &AgreementRule,
+    ) -> Adjective {
+        let (gender, number) = self.coordinated_agreement(nouns, rule);
+
+        self.adjective(adjective, case, &number, &gender, &Degree::Positive)
+    }
+
+    pub fn noun(&self, word: &str, case: &Case, number: &Number) -> Noun {
         let recordik = self.noun_map.get(word);
 
         match recordik {
+            Some(record) if record.indeclinable => {
+                let form = if record.nom_sg.is_empty() {
+                    record.word.clone()
+                } else {
+                    record.nom_sg.clone()
+                };
+                (form, record.gender.clone())
+            }
             Some(record) => {
                 let mut response = match number {
                     Number::Singular => match case {
@@ -995,11 +1466,11 @@ impl Latin {
                     },
                 };
 
-                if case == &Case::Loc && (response.0 == "" || response.0 == "-") {
-                    response.0 = format!("{}", record.abl_sg.clone());
+                if case == &Case::Loc && (response.0.is_empty() || response.0 == "-") {
+                    response.0 = record.abl_sg.clone();
                 }
 
-                if (response.0 == "" || response.0 == "-") {
+                if response.0.is_empty() || response.0 == "-" {
                     response = Latin::guess_noun(word, case, number);
                 }
 
@@ -1010,11 +1481,47 @@ impl Latin {
     }
 
     pub fn new(noun_path: String, adjective_path: String, verb_path: String) -> Self {
-        Latin {
+        let mut latin = Latin {
             noun_map: Latin::load_nouns_from_csv(noun_path),
             adj_map: Latin::load_adjectives_from_csv(adjective_path),
             verb_map: Latin::load_verbs_from_csv(verb_path),
+            glossary: HashMap::new(),
+        };
+
+        for (word, gender) in INDECLINABLE_NOUNS {
+            latin.register_indeclinable_noun(word, gender);
         }
+        for word in INDECLINABLE_ADJECTIVES {
+            latin.register_indeclinable_adjective(word);
+        }
+
+        latin
+    }
+
+    // Marks a noun as indeclinable, adding it to the dictionary if it is missing.
+    // Useful for genus names cited as indeclinable such as "Ginkgo"
+    pub fn register_indeclinable_noun(&mut self, word: &str, gender: Gender) {
+        let record = self
+            .noun_map
+            .entry(word.to_string())
+            .or_insert_with(|| NounRecord {
+                word: word.to_string(),
+                nom_sg: word.to_string(),
+                ..Default::default()
+            });
+        record.gender = gender;
+        record.indeclinable = true;
+    }
+
+    pub fn register_indeclinable_adjective(&mut self, word: &str) {
+        let record = self
+            .adj_map
+            .entry(word.to_string())
+            .or_insert_with(|| AdjectiveRecord {
+                word: word.to_string(),
+                ..Default::default()
+            });
+        record.indeclinable = true;
     }
 
     //"nouns.csv"
@@ -1064,31 +1571,38 @@ impl Latin {
         case: &Case,
         number: &Number,
         gender: &Gender,
+        degree: &Degree,
     ) -> Adjective {
-        let defik = AdjectiveRecord::default();
-
         let recordik = self.adj_map.get(word);
 
-        match recordik {
-            Some(record) => match gender {
-                Gender::Masculine => match number {
-                    Number::Singular => match case {
-                        Case::Nom => record.nom_sg_masc.clone(),
-                        Case::Gen => record.gen_sg_masc.clone(),
-                        Case::Dat => record.dat_sg_masc.clone(),
-                        Case::Acc => record.acc_sg_masc.clone(),
-                        Case::Abl => record.abl_sg_masc.clone(),
-                        _ => record.abl_sg_masc.clone(),
-                    },
-                    Number::Plural => match case {
-                        Case::Nom => record.nom_pl_masc.clone(),
-                        Case::Gen => record.gen_pl_masc.clone(),
-                        Case::Dat => record.dat_pl_masc.clone(),
-                        Case::Acc => record.acc_pl_masc.clone(),
-                        Case::Abl => record.abl_pl_masc.clone(),
-                        _ => record.abl_pl_masc.clone(),
-                    },
-                },
+        let form = match degree {
+            Degree::Positive => None,
+            _ if recordik.is_some_and(|record| record.indeclinable) => return word.to_string(),
+            Degree::Comparative => Some(self.comparative(word)),
+            Degree::Superlative => Some(self.superlative(word)),
+        };
+
+        if let Some(form) = form {
+            if let Some((adverb, positive)) = Latin::periphrastic_comparison(&form) {
+                let positive = self.adjective(positive, case, number, gender, &Degree::Positive);
+                return format!("{adverb} {positive}");
+            }
+
+            return match degree {
+                Degree::Comparative => Latin::decline_comparative(&form, case, number, gender),
+                _ => Latin::guess_adjective(&form, case, number, gender),
+            };
+        }
+
+        let pronominal = recordik.is_some_and(|record| record.pronominal)
+            || PRONOMINAL_ADJECTIVES.contains(&word);
+        if pronominal && number == &Number::Singular && matches!(case, Case::Gen | Case::Dat) {
+            return Latin::guess_pronominal_adjective(word, case, number, gender);
+        }
+
+        let response = match recordik {
+            Some(record) if record.indeclinable => return record.word.clone(),
+            Some(record) => match gender.agreement() {
                 Gender::Feminine => match number {
                     Number::Singular => match case {
                         Case::Nom => record.nom_sg_fem.clone(),
@@ -1096,7 +1610,8 @@ impl Latin {
                         Case::Dat => record.dat_sg_fem.clone(),
                         Case::Acc => record.acc_sg_fem.clone(),
                         Case::Abl => record.abl_sg_fem.clone(),
-                        _ => record.abl_sg_fem.clone(),
+                        Case::Voc => record.voc_sg_fem.clone(),
+                        Case::Loc => record.loc_sg_fem.clone(),
                     },
                     Number::Plural => match case {
                         Case::Nom => record.nom_pl_fem.clone(),
@@ -1104,7 +1619,8 @@ impl Latin {
                         Case::Dat => record.dat_pl_fem.clone(),
                         Case::Acc => record.acc_pl_fem.clone(),
                         Case::Abl => record.abl_pl_fem.clone(),
-                        _ => record.abl_pl_fem.clone(),
+                        Case::Voc => record.voc_pl_fem.clone(),
+                        Case::Loc => record.loc_pl_fem.clone(),
                     },
                 },
                 Gender::Neuter => match number {
@@ -1114,7 +1630,8 @@ impl Latin {
                         Case::Dat => record.dat_sg_neut.clone(),
                         Case::Acc => record.acc_sg_neut.clone(),
                         Case::Abl => record.abl_sg_neut.clone(),
-                        _ => record.abl_sg_neut.clone(),
+                        Case::Voc => record.voc_sg_neut.clone(),
+                        Case::Loc => record.loc_sg_neut.clone(),
                     },
                     Number::Plural => match case {
                         Case::Nom => record.nom_pl_neut.clone(),
@@ -1122,11 +1639,387 @@ impl Latin {
                         Case::Dat => record.dat_pl_neut.clone(),
                         Case::Acc => record.acc_pl_neut.clone(),
                         Case::Abl => record.abl_pl_neut.clone(),
-                        _ => record.abl_pl_neut.clone(),
+                        Case::Voc => record.voc_pl_neut.clone(),
+                        Case::Loc => record.loc_pl_neut.clone(),
+                    },
+                },
+                _ => match number {
+                    Number::Singular => match case {
+                        Case::Nom => record.nom_sg_masc.clone(),
+                        Case::Gen => record.gen_sg_masc.clone(),
+                        Case::Dat => record.dat_sg_masc.clone(),
+                        Case::Acc => record.acc_sg_masc.clone(),
+                        Case::Abl => record.abl_sg_masc.clone(),
+                        Case::Voc => record.voc_sg_masc.clone(),
+                        Case::Loc => record.loc_sg_masc.clone(),
+                    },
+                    Number::Plural => match case {
+                        Case::Nom => record.nom_pl_masc.clone(),
+                        Case::Gen => record.gen_pl_masc.clone(),
+                        Case::Dat => record.dat_pl_masc.clone(),
+                        Case::Acc => record.acc_pl_masc.clone(),
+                        Case::Abl => record.abl_pl_masc.clone(),
+                        Case::Voc => record.voc_pl_masc.clone(),
+                        Case::Loc => record.loc_pl_masc.clone(),
                     },
                 },
             },
-            None => Latin::guess_adjective(word, case, number, gender),
+            None => return Latin::guess_adjective(word, case, number, gender),
+        };
+
+        if !response.is_empty() && response != "-" {
+            return response;
+        }
+
+        match case {
+            Case::Voc => Latin::vocative_from_forms(
+                &self.adjective(word, &Case::Nom, number, gender, degree),
+                &self.adjective(word, &Case::Gen, number, gender, degree),
+                number,
+                gender,
+            ),
+            Case::Loc => Latin::locative_from_forms(
+                &self.adjective(word, &Case::Gen, number, gender, degree),
+                &self.adjective(word, &Case::Abl, number, gender, degree),
+                number,
+            ),
+            _ => response,
+        }
+    }
+
+    // Masculine nominative singular of the comparative, "latus" -> "latior"
+    pub fn comparative(&self, word: &str) -> String {
+        match self.adj_map.get(word) {
+            Some(record) if !record.comparative.is_empty() && record.comparative != "-" => {
+                record.comparative.clone()
+            }
+            _ => Latin::guess_comparative(word),
+        }
+    }
+
+    // Masculine nominative singular of the superlative, "latus" -> "latissimus"
+    pub fn superlative(&self, word: &str) -> String {
+        match self.adj_map.get(word) {
+            Some(record) if !record.superlative.is_empty() && record.superlative != "-" => {
+                record.superlative.clone()
+            }
+            _ => Latin::guess_superlative(word),
+        }
+    }
+
+    pub fn adverb(&self, word: &str, degree: &Degree) -> String {
+        let irregular = IRREGULAR_ADVERBS.iter().any(|adverbs| adverbs.0 == word)
+            || IM_ADVERBS.iter().any(|adverbs| adverbs.0 == word);
+        if irregular {
+            return Latin::guess_adverb(word, degree);
+        }
+
+        let positive = |word: &str| self.adverb(word, &Degree::Positive);
+        match degree {
+            // Some adverbs have alternatives, "misere or miseriter", the first is used
+            Degree::Positive => match self.adj_map.get(word) {
+                Some(record) if !record.adverb.is_empty() && record.adverb != "-" => record
+                    .adverb
+                    .split(" or ")
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                _ => Latin::guess_adverb(word, degree),
+            },
+            Degree::Comparative => {
+                Latin::compared_adverb(&self.comparative(word), degree, positive)
+            }
+            Degree::Superlative => {
+                Latin::compared_adverb(&self.superlative(word), degree, positive)
+            }
+        }
+    }
+
+    // Adverb from the masculine comparative or superlative of its adjective. The comparative
+    // adverb is the neuter accusative of the comparative, "latius", the superlative one ends
+    // in -e, "latissime", and periphrastic forms take the positive adverb, "magis idonee"
+    fn compared_adverb(form: &str, degree: &Degree, positive: impl Fn(&str) -> String) -> String {
+        if let Some((adverb, word)) = Latin::periphrastic_comparison(form) {
+            return format!("{adverb} {}", positive(word));
+        }
+
+        match degree {
+            Degree::Comparative => {
+                Latin::decline_comparative(form, &Case::Acc, &Number::Singular, &Gender::Neuter)
+            }
+            _ => Latin::guess_adverb(form, &Degree::Positive),
+        }
+    }
+
+    pub fn guess_adverb(word: &str, degree: &Degree) -> String {
+        for (adjective, positive, comparative, superlative) in IRREGULAR_ADVERBS {
+            if word == adjective {
+                return match degree {
+                    Degree::Positive => positive,
+                    Degree::Comparative => comparative,
+                    Degree::Superlative => superlative,
+                }
+                .to_string();
+            }
+        }
+
+        let positive = |word: &str| Latin::guess_adverb(word, &Degree::Positive);
+        match degree {
+            Degree::Comparative => {
+                return Latin::compared_adverb(&Latin::guess_comparative(word), degree, positive)
+            }
+            Degree::Superlative => {
+                return Latin::compared_adverb(&Latin::guess_superlative(word), degree, positive)
+            }
+            Degree::Positive => {}
+        }
+
+        if let Some((_, adverb)) = IM_ADVERBS.iter().find(|adverbs| adverbs.0 == word) {
+            return adverb.to_string();
+        }
+
+        let stem = Latin::adjective_stem(word);
+
+        if word.ends_with("us") {
+            format!("{stem}e")
+        } else if word.ends_with("er") {
+            if THIRD_DECLENSION_ER.contains(&word) || word.ends_with("ster") {
+                format!("{stem}iter")
+            } else {
+                format!("{stem}e")
+            }
+        } else if let Some(stem) = stem.strip_suffix("nt") {
+            format!("{stem}nter")
+        } else {
+            format!("{stem}iter")
+        }
+    }
+
+    pub fn guess_comparative(word: &str) -> String {
+        for (positive, comparative, _) in IRREGULAR_COMPARISON {
+            if word == positive {
+                return comparative.to_string();
+            }
+        }
+
+        if Latin::compares_periphrastically(word) {
+            return format!("magis {word}");
+        }
+
+        format!("{}ior", Latin::adjective_stem(word))
+    }
+
+    pub fn guess_superlative(word: &str) -> String {
+        for (positive, _, superlative) in IRREGULAR_COMPARISON {
+            if word == positive {
+                return superlative.to_string();
+            }
+        }
+
+        if Latin::compares_periphrastically(word) {
+            format!("maxime {word}")
+        } else if word.ends_with("er") {
+            format!("{word}rimus")
+        } else if LIS_SUPERLATIVES.contains(&word) {
+            format!("{}limus", Latin::adjective_stem(word))
+        } else {
+            format!("{}issimus", Latin::adjective_stem(word))
+        }
+    }
+
+    // Adjectives with a vowel before -us compare with "magis" and "maxime", "idoneus",
+    // "pius", "arduus", but not those in -quus, "antiquior"
+    pub fn compares_periphrastically(word: &str) -> bool {
+        ["eus", "ius", "uus"]
+            .iter()
+            .any(|ending| word.ends_with(ending))
+            && !word.ends_with("quus")
+    }
+
+    // Splits "magis idoneus" into the adverb and the positive
+    pub fn periphrastic_comparison(form: &str) -> Option<(&str, &str)> {
+        form.split_once(' ')
+            .filter(|(adverb, _)| PERIPHRASTIC_ADVERBS.contains(adverb))
+    }
+
+    // Stem of an adjective as seen in its oblique cases, "integer" -> "integr", "felix" -> "felic"
+    pub fn adjective_stem(word: &str) -> String {
+        let keeps_e = ER_KEEPS_E.contains(&word)
+            || word.ends_with("fer")
+            || (word.ends_with("iger") && word != "niger");
+
+        if let Some(stem) = word.strip_suffix("us") {
+            stem.to_string()
+        } else if let Some(stem) = word.strip_suffix("er") {
+            if keeps_e {
+                word.to_string()
+            } else {
+                format!("{stem}r")
+            }
+        } else if let Some(stem) = word.strip_suffix("is") {
+            stem.to_string()
+        } else if let Some(stem) = word.strip_suffix("ns") {
+            format!("{stem}nt")
+        } else if let Some(stem) = word.strip_suffix("des") {
+            format!("{stem}d")
+        } else if let Some(stem) = word.strip_suffix('x') {
+            let stem = stem
+                .strip_suffix('e')
+                .map_or(stem.to_string(), |s| format!("{s}i"));
+            format!("{stem}c")
+        } else {
+            word.to_string()
+        }
+    }
+
+    // Declines a comparative on the third declension pattern, "latior, latius, latioris"
+    pub fn decline_comparative(
+        comparative: &str,
+        case: &Case,
+        number: &Number,
+        gender: &Gender,
+    ) -> Adjective {
+        let (stem, masc_ends, neut_ends) = match comparative.strip_suffix("us") {
+            Some(stem) if comparative == "plus" => (stem, &PLUS_ENDINGS, &PLUS_NEUTER_ENDINGS),
+            _ => (
+                comparative.strip_suffix("or").unwrap_or(comparative),
+                &OR_DECLENSION_ENDINGS,
+                &COMPARATIVE_NEUTER_ENDINGS,
+            ),
+        };
+
+        let ending = match gender.agreement() {
+            Gender::Neuter => neut_ends.ending(case, number),
+            _ => masc_ends.ending(case, number),
+        };
+
+        format!("{stem}{ending}")
+    }
+
+    // "totus, tota, totum", genitive "totius", dative "toti"
+    pub fn guess_pronominal_adjective(
+        word: &str,
+        case: &Case,
+        number: &Number,
+        gender: &Gender,
+    ) -> Adjective {
+        // "alter" keeps its e, "alterius", where "neuter" and "uter" lose it, "neutrius"
+        let stem = match word {
+            "alter" => word.to_string(),
+            _ => Latin::adjective_stem(word),
+        };
+        let gender = gender.agreement();
+
+        if number == &Number::Singular {
+            match case {
+                Case::Gen if word == "alius" => return "alterius".to_string(),
+                Case::Gen => return format!("{stem}ius"),
+                Case::Dat => return format!("{stem}i"),
+                Case::Nom | Case::Acc if word == "alius" && gender == Gender::Neuter => {
+                    return "aliud".to_string()
+                }
+                Case::Nom | Case::Voc if gender == Gender::Masculine => return word.to_string(),
+                _ => {}
+            }
+        }
+
+        let ending = match gender {
+            Gender::Feminine => A_DECLENSION_ENDINGS.ending(case, number),
+            Gender::Neuter => UM_DECLENSION_ENDINGS.ending(case, number),
+            _ => US_DECLENSION_ENDINGS.ending(case, number),
+        };
+
+        format!("{stem}{ending}")
+    }
+
+    // The vocative equals the nominative except for masculine singulars in -us of the second
+    // declension, "carus" -> "care", "unus" -> "une", "egregius" -> "egregie", but "vetus"
+    // stays "vetus"
+    pub fn vocative_from_forms(
+        nominative: &str,
+        genitive: &str,
+        number: &Number,
+        gender: &Gender,
+    ) -> String {
+        // Pronominal adjectives have a genitive in -ius but are of the second declension as well
+        let second = (genitive.ends_with('i') && !genitive.ends_with("is"))
+            || PRONOMINAL_ADJECTIVES.contains(&nominative);
+        if number != &Number::Singular || gender.agreement() != Gender::Masculine || !second {
+            return nominative.to_string();
+        }
+
+        match nominative.strip_suffix("us") {
+            Some("me") => "mi".to_string(),
+            Some(stem) => format!("{stem}e"),
+            None => nominative.to_string(),
+        }
+    }
+
+    // The locative singular of the first and second declensions looks like the genitive,
+    // everywhere else it falls together with the ablative
+    pub fn locative_from_forms(genitive: &str, ablative: &str, number: &Number) -> String {
+        let first_or_second = genitive.ends_with("ae") || genitive.ends_with('i');
+
+        if number == &Number::Singular && first_or_second {
+            genitive.to_string()
+        } else {
+            ablative.to_string()
+        }
+    }
+
+    // The future from the present stem, -bo in the first and second conjugations and -am in
+    // the third and fourth, "habebo", "sumam". None for deponents and the like
+    pub fn future_active(record: &VerbRecord, number: &Number, person: &Person) -> Option<String> {
+        let present = record.indicative_active_present_singular_first.as_str();
+        let infinitive = record.present_infinitive.as_str();
+
+        // "sum" and its compounds, "ero", "adero", "potero"
+        if let Some(prefix) = present.strip_suffix("sum") {
+            let stem = match infinitive.strip_suffix("esse") {
+                Some(stem) => format!("{stem}er"),
+                None => format!("{}ter", prefix.strip_suffix('s')?),
+            };
+            let endings = ["o", "is", "it", "imus", "itis", "unt"];
+            return Some(format!(
+                "{stem}{}",
+                Latin::personal_ending(&endings, number, person)
+            ));
+        }
+
+        let bo = ["bo", "bis", "bit", "bimus", "bitis", "bunt"];
+        let am = ["am", "es", "et", "emus", "etis", "ent"];
+        let (stem, endings) = match infinitive.strip_suffix("re") {
+            // "amare", "habere", and "ire" with its compounds, "exibo"
+            Some(stem)
+                if infinitive.ends_with("are")
+                    || (present.ends_with("eo") && infinitive.ends_with("ere"))
+                    || (present.ends_with("eo") && infinitive.ends_with("ire")) =>
+            {
+                (stem, &bo)
+            }
+            _ => (present.strip_suffix('o')?, &am),
+        };
+
+        Some(format!(
+            "{stem}{}",
+            Latin::personal_ending(endings, number, person)
+        ))
+    }
+
+    // Picks the ending for the person from the six of a tense, singular before plural
+    pub fn personal_ending<'a>(
+        endings: &[&'a str; 6],
+        number: &Number,
+        person: &Person,
+    ) -> &'a str {
+        let person = match person {
+            Person::First => 0,
+            Person::Second => 1,
+            Person::Third => 2,
+        };
+        match number {
+            Number::Singular => endings[person],
+            Number::Plural => endings[person + 3],
         }
     }
 
@@ -1190,18 +2083,33 @@ impl Latin {
                             }
                         },
                     },
-                    Tense::Future => match number {
-                        Number::Singular => match person {
-                            Person::First => record.indicative_active_future_singular_first.clone(),
-                            Person::Second => {
-                                record.indicative_active_future_singular_second.clone()
-                            }
-                            Person::Third => record.indicative_active_future_singular_third.clone(),
-                        },
-                        Number::Plural => match person {
-                            Person::First => record.indicative_active_future_plural_first.clone(),
-                            Person::Second => record.indicative_active_future_plural_second.clone(),
-                            Person::Third => record.indicative_active_future_plural_third.clone(),
+                    // The future columns of the dictionary mostly hold the future perfect,
+                    // they are only used for verbs without a regular present stem
+                    Tense::Future => match Latin::future_active(record, number, person) {
+                        Some(future) => future,
+                        None => match number {
+                            Number::Singular => match person {
+                                Person::First => {
+                                    record.indicative_active_future_singular_first.clone()
+                                }
+                                Person::Second => {
+                                    record.indicative_active_future_singular_second.clone()
+                                }
+                                Person::Third => {
+                                    record.indicative_active_future_singular_third.clone()
+                                }
+                            },
+                            Number::Plural => match person {
+                                Person::First => {
+                                    record.indicative_active_future_plural_first.clone()
+                                }
+                                Person::Second => {
+                                    record.indicative_active_future_plural_second.clone()
+                                }
+                                Person::Third => {
+                                    record.indicative_active_future_plural_third.clone()
+                                }
+                            },
                         },
                     },
                     Tense::Perfect => match number {
@@ -1248,9 +2156,14 @@ impl Latin {
                             }
                         },
                     },
-                    Tense::FuturePerfect => {
-                        todo!("IMPLEMENT FUTURE PERFECT")
-                    }
+                    // Built on the perfect stem, "florui" gives "floruero"
+                    Tense::FuturePerfect => match record.perfect_active.strip_suffix('i') {
+                        Some(stem) => {
+                            let endings = ["ero", "eris", "erit", "erimus", "eritis", "erint"];
+                            format!("{stem}{}", Latin::personal_ending(&endings, number, person))
+                        }
+                        None => String::new(),
+                    },
                 },
                 _ => todo!("IMPLEMENT PASSIVE VOICE"),
             },
@@ -1264,6 +2177,118 @@ impl Latin {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::OnceLock;
+
+    // The dictionaries are loaded once and shared by the tests of every module
+    pub(crate) fn latin() -> &'static Latin {
+        static LATIN: OnceLock<Latin> = OnceLock::new();
+        LATIN.get_or_init(|| {
+            let mut latin = Latin::new(
+                "nouns.csv".to_string(),
+                "adjectives.csv".to_string(),
+                "verbs.csv".to_string(),
+            );
+            latin.load_glossary("glossary.csv".to_string());
+            latin
+        })
+    }
+
+    #[test]
+    fn periphrastic_comparative_declines_the_positive() {
+        let latin = latin();
+        let idoneis = latin.adjective(
+            "idoneus",
+            &Case::Abl,
+            &Number::Plural,
+            &Gender::Neuter,
+            &Degree::Comparative,
+        );
+        assert_eq!(idoneis, "magis idoneis");
+
+        let florea = latin.adjective(
+            "floreus",
+            &Case::Nom,
+            &Number::Singular,
+            &Gender::Feminine,
+            &Degree::Superlative,
+        );
+        assert_eq!(florea, "maxime florea");
+    }
+
+    #[test]
+    fn guessed_comparative_of_vowel_stems_is_periphrastic() {
+        assert_eq!(Latin::guess_comparative("roseus"), "magis roseus");
+        assert_eq!(Latin::guess_superlative("arduus"), "maxime arduus");
+        assert_eq!(Latin::guess_comparative("antiquus"), "antiquior");
+    }
+
+    #[test]
+    fn adverbs() {
+        let latin = latin();
+        assert_eq!(
+            latin.adverb("idoneus", &Degree::Comparative),
+            "magis idonee"
+        );
+        assert_eq!(
+            latin.adverb("idoneus", &Degree::Superlative),
+            "maxime idonee"
+        );
+        assert_eq!(latin.adverb("miser", &Degree::Positive), "misere");
+        assert_eq!(latin.adverb("latus", &Degree::Comparative), "latius");
+    }
+
+    #[test]
+    fn vocative_of_the_second_declension_only() {
+        let latin = latin();
+        let vocative = |word: &str| {
+            latin.adjective(
+                word,
+                &Case::Voc,
+                &Number::Singular,
+                &Gender::Masculine,
+                &Degree::Positive,
+            )
+        };
+        assert_eq!(vocative("carus"), "care");
+        assert_eq!(vocative("egregius"), "egregie");
+        assert_eq!(vocative("vetus"), "vetus");
+        assert_eq!(vocative("unus"), "une");
+        assert_eq!(vocative("solus"), "sole");
+        assert_eq!(vocative("totus"), "tote");
+    }
+
+    #[test]
+    fn ambiguous_genders_agree_with_the_first_listed() {
+        let feminine_or_neuter = Gender::Ambiguous(&[Gender::Feminine, Gender::Neuter]);
+        assert_eq!(feminine_or_neuter.agreement(), Gender::Feminine);
+        assert_eq!(Gender::Ambiguous(&[]).agreement(), Gender::Masculine);
+        assert_eq!(Gender::Common.agreement(), Gender::Masculine);
+    }
+
+    #[test]
+    fn pronominal_adjectives() {
+        let genitive = |word: &str| {
+            Latin::guess_adjective(word, &Case::Gen, &Number::Singular, &Gender::Feminine)
+        };
+        assert_eq!(genitive("alter"), "alterius");
+        assert_eq!(genitive("neuter"), "neutrius");
+        assert_eq!(genitive("alius"), "alterius");
+        assert_eq!(
+            Latin::guess_adjective("alter", &Case::Abl, &Number::Plural, &Gender::Feminine),
+            "alteris"
+        );
+    }
+
+    #[test]
+    fn celer_keeps_its_e() {
+        assert_eq!(Latin::guess_comparative("celer"), "celerior");
+        assert_eq!(Latin::guess_adverb("celer", &Degree::Positive), "celeriter");
+    }
+}
+
 /*
 
 fn main() {
@@ -0,0 +1,188 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Unit {
+    Millimeter,
+    Centimeter,
+    Decimeter,
+    Meter,
+}
+
+impl Unit {
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Unit::Millimeter => "mm",
+            Unit::Centimeter => "cm",
+            Unit::Decimeter => "dm",
+            Unit::Meter => "m",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Dimension {
+    Longus,
+    Latus,
+    Altus,
+    Crassus,
+    // Ablative of the noun, "2 cm diametro", does not agree with anything
+    Diametro,
+}
+
+impl Dimension {
+    // The adjective that agrees with the measured organ, None for "diametro"
+    pub fn adjective(&self) -> Option<&'static str> {
+        match self {
+            Dimension::Longus => Some("longus"),
+            Dimension::Latus => Some("latus"),
+            Dimension::Altus => Some("altus"),
+            Dimension::Crassus => Some("crassus"),
+            Dimension::Diametro => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Range {
+    // "3 cm"
+    Exact(f64),
+    // "3–5 cm"
+    Between(f64, f64),
+    // "ad 2 m"
+    Ad(f64),
+    // "usque ad 10 mm"
+    UsqueAd(f64),
+}
+
+// "3–5 cm longa", a measurement without dimension is only the figures, "usque ad 10 mm"
+#[derive(Debug, PartialEq, Clone)]
+pub struct Measurement {
+    pub range: Range,
+    pub unit: Unit,
+    pub dimension: Option<Dimension>,
+}
+
+impl Latin {
+    // The dimension agrees with the measured noun in case, number and gender
+    pub fn measurement(
+        &self,
+        measurement: &Measurement,
+        case: &Case,
+        number: &Number,
+        gender: &Gender,
+    ) -> String {
+        let figures = match measurement.range {
+            Range::Exact(value) => format!("{value}"),
+            Range::Between(from, to) => format!("{from}–{to}"),
+            Range::Ad(value) => format!("ad {value}"),
+            Range::UsqueAd(value) => format!("usque ad {value}"),
+        };
+        let mut response = format!("{} {}", figures, measurement.unit.abbreviation());
+
+        if let Some(dimension) = &measurement.dimension {
+            let dimension = match dimension.adjective() {
+                Some(adjective) => {
+                    self.adjective(adjective, case, number, gender, &Degree::Positive)
+                }
+                None => "diametro".to_string(),
+            };
+            response = format!("{response} {dimension}");
+        }
+
+        response
+    }
+
+    // "folia 3–5 cm longa, 1–2 cm lata"
+    pub fn measured_organ(
+        &self,
+        organ: &ComplexNoun,
+        case: &Case,
+        number: &Number,
+        measurements: &[Measurement],
+    ) -> String {
+        let gender = self.agreement_gender(organ);
+
+        let measurements: Vec<String> = measurements
+            .iter()
+            .map(|m| self.measurement(m, case, number, &gender))
+            .collect();
+
+        format!(
+            "{} {}",
+            self.complex_noun(organ, case, number),
+            measurements.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    fn measurement(range: Range, unit: Unit, dimension: Option<Dimension>) -> Measurement {
+        Measurement {
+            range,
+            unit,
+            dimension,
+        }
+    }
+
+    #[test]
+    fn ranges_agree_with_the_organ() {
+        let folium = ComplexNoun {
+            head_noun: "folium".to_string(),
+            ..Default::default()
+        };
+        let measurements = [
+            measurement(
+                Range::Between(3.0, 5.0),
+                Unit::Centimeter,
+                Some(Dimension::Longus),
+            ),
+            measurement(
+                Range::Between(1.0, 2.0),
+                Unit::Centimeter,
+                Some(Dimension::Latus),
+            ),
+        ];
+
+        assert_eq!(
+            latin().measured_organ(&folium, &Case::Nom, &Number::Plural, &measurements),
+            "folia 3–5 cm longa, 1–2 cm lata"
+        );
+    }
+
+    #[test]
+    fn upper_limits() {
+        let alta = measurement(Range::Ad(2.0), Unit::Meter, Some(Dimension::Altus));
+        assert_eq!(
+            latin().measurement(&alta, &Case::Nom, &Number::Singular, &Gender::Feminine),
+            "ad 2 m alta"
+        );
+
+        let figures = measurement(Range::UsqueAd(10.0), Unit::Millimeter, None);
+        assert_eq!(
+            latin().measurement(&figures, &Case::Nom, &Number::Singular, &Gender::Feminine),
+            "usque ad 10 mm"
+        );
+    }
+
+    #[test]
+    fn diametro_does_not_agree() {
+        let flos = ComplexNoun {
+            head_noun: "flos".to_string(),
+            ..Default::default()
+        };
+        let diametro = measurement(
+            Range::Exact(2.5),
+            Unit::Centimeter,
+            Some(Dimension::Diametro),
+        );
+
+        assert_eq!(
+            latin().measured_organ(&flos, &Case::Abl, &Number::Plural, &[diametro]),
+            "floribus 2.5 cm diametro"
+        );
+    }
+}
@@ -0,0 +1,602 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum NumeralKind {
+    // unus, duo, tres
+    Cardinal,
+    // primus, secundus, tertius
+    Ordinal,
+    // singuli, bini, terni
+    Distributive,
+    // semel, bis, ter
+    Multiplicative,
+}
+
+// Lemmas for 1-9, 11-17, the tens and the hundreds of each kind of numeral, 0 is unused.
+// Ordinals and distributives are given as -us adjectives and declined from there
+pub const CARDINAL_UNITS: [&str; 10] = [
+    "", "unus", "duo", "tres", "quattuor", "quinque", "sex", "septem", "octo", "novem",
+];
+pub const CARDINAL_TEENS: [&str; 10] = [
+    "",
+    "undecim",
+    "duodecim",
+    "tredecim",
+    "quattuordecim",
+    "quindecim",
+    "sedecim",
+    "septendecim",
+    "",
+    "",
+];
+pub const CARDINAL_TENS: [&str; 11] = [
+    "",
+    "decem",
+    "viginti",
+    "triginta",
+    "quadraginta",
+    "quinquaginta",
+    "sexaginta",
+    "septuaginta",
+    "octoginta",
+    "nonaginta",
+    "centum",
+];
+pub const CARDINAL_HUNDREDS: [&str; 10] = [
+    "",
+    "centum",
+    "ducenti",
+    "trecenti",
+    "quadringenti",
+    "quingenti",
+    "sescenti",
+    "septingenti",
+    "octingenti",
+    "nongenti",
+];
+
+pub const ORDINAL_UNITS: [&str; 10] = [
+    "", "primus", "secundus", "tertius", "quartus", "quintus", "sextus", "septimus", "octavus",
+    "nonus",
+];
+pub const ORDINAL_TEENS: [&str; 10] = [
+    "",
+    "undecimus",
+    "duodecimus",
+    "tertius decimus",
+    "quartus decimus",
+    "quintus decimus",
+    "sextus decimus",
+    "septimus decimus",
+    "",
+    "",
+];
+pub const ORDINAL_TENS: [&str; 11] = [
+    "",
+    "decimus",
+    "vicesimus",
+    "tricesimus",
+    "quadragesimus",
+    "quinquagesimus",
+    "sexagesimus",
+    "septuagesimus",
+    "octogesimus",
+    "nonagesimus",
+    "centesimus",
+];
+pub const ORDINAL_HUNDREDS: [&str; 10] = [
+    "",
+    "centesimus",
+    "ducentesimus",
+    "trecentesimus",
+    "quadringentesimus",
+    "quingentesimus",
+    "sescentesimus",
+    "septingentesimus",
+    "octingentesimus",
+    "nongentesimus",
+];
+
+pub const DISTRIBUTIVE_UNITS: [&str; 10] = [
+    "",
+    "singulus",
+    "binus",
+    "ternus",
+    "quaternus",
+    "quinus",
+    "senus",
+    "septenus",
+    "octonus",
+    "novenus",
+];
+pub const DISTRIBUTIVE_TEENS: [&str; 10] = [
+    "",
+    "undenus",
+    "duodenus",
+    "ternus denus",
+    "quaternus denus",
+    "quinus denus",
+    "senus denus",
+    "septenus denus",
+    "",
+    "",
+];
+pub const DISTRIBUTIVE_TENS: [&str; 11] = [
+    "",
+    "denus",
+    "vicenus",
+    "tricenus",
+    "quadragenus",
+    "quinquagenus",
+    "sexagenus",
+    "septuagenus",
+    "octogenus",
+    "nonagenus",
+    "centenus",
+];
+pub const DISTRIBUTIVE_HUNDREDS: [&str; 10] = [
+    "",
+    "centenus",
+    "ducenus",
+    "trecenus",
+    "quadringenus",
+    "quingenus",
+    "sescenus",
+    "septingenus",
+    "octingenus",
+    "nongenus",
+];
+
+pub const MULTIPLICATIVE_UNITS: [&str; 10] = [
+    "",
+    "semel",
+    "bis",
+    "ter",
+    "quater",
+    "quinquies",
+    "sexies",
+    "septies",
+    "octies",
+    "novies",
+];
+pub const MULTIPLICATIVE_TEENS: [&str; 10] = [
+    "",
+    "undecies",
+    "duodecies",
+    "terdecies",
+    "quater decies",
+    "quindecies",
+    "sedecies",
+    "septies decies",
+    "",
+    "",
+];
+pub const MULTIPLICATIVE_TENS: [&str; 11] = [
+    "",
+    "decies",
+    "vicies",
+    "tricies",
+    "quadragies",
+    "quinquagies",
+    "sexagies",
+    "septuagies",
+    "octogies",
+    "nonagies",
+    "centies",
+];
+pub const MULTIPLICATIVE_HUNDREDS: [&str; 10] = [
+    "",
+    "centies",
+    "ducenties",
+    "trecenties",
+    "quadringenties",
+    "quingenties",
+    "sescenties",
+    "septingenties",
+    "octingenties",
+    "nongenties",
+];
+
+pub const ROMAN_NUMERALS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+// duo and tres only exist in the plural
+pub const DUO_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "duo",
+        acc_sg: "duos",
+        gen_sg: "duorum",
+        dat_sg: "duobus",
+        abl_sg: "duobus",
+
+        nom_pl: "duo",
+        acc_pl: "duos",
+        gen_pl: "duorum",
+        dat_pl: "duobus",
+        abl_pl: "duobus",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "duae",
+        acc_sg: "duas",
+        gen_sg: "duarum",
+        dat_sg: "duabus",
+        abl_sg: "duabus",
+
+        nom_pl: "duae",
+        acc_pl: "duas",
+        gen_pl: "duarum",
+        dat_pl: "duabus",
+        abl_pl: "duabus",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "duo",
+        acc_sg: "duo",
+        gen_sg: "duorum",
+        dat_sg: "duobus",
+        abl_sg: "duobus",
+
+        nom_pl: "duo",
+        acc_pl: "duo",
+        gen_pl: "duorum",
+        dat_pl: "duobus",
+        abl_pl: "duobus",
+    },
+];
+
+pub const TRES_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "tres",
+        acc_sg: "tres",
+        gen_sg: "trium",
+        dat_sg: "tribus",
+        abl_sg: "tribus",
+
+        nom_pl: "tres",
+        acc_pl: "tres",
+        gen_pl: "trium",
+        dat_pl: "tribus",
+        abl_pl: "tribus",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "tres",
+        acc_sg: "tres",
+        gen_sg: "trium",
+        dat_sg: "tribus",
+        abl_sg: "tribus",
+
+        nom_pl: "tres",
+        acc_pl: "tres",
+        gen_pl: "trium",
+        dat_pl: "tribus",
+        abl_pl: "tribus",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "tria",
+        acc_sg: "tria",
+        gen_sg: "trium",
+        dat_sg: "tribus",
+        abl_sg: "tribus",
+
+        nom_pl: "tria",
+        acc_pl: "tria",
+        gen_pl: "trium",
+        dat_pl: "tribus",
+        abl_pl: "tribus",
+    },
+];
+
+// "milia" is a neuter plural noun, "duo milia"
+pub const MILIA_ENDINGS: CaseEndings = CaseEndings {
+    gender: Gender::Neuter,
+    nom_sg: "mille",
+    acc_sg: "mille",
+    gen_sg: "mille",
+    dat_sg: "mille",
+    abl_sg: "mille",
+
+    nom_pl: "milia",
+    acc_pl: "milia",
+    gen_pl: "milium",
+    dat_pl: "milibus",
+    abl_pl: "milibus",
+};
+
+impl Latin {
+    // Numerals up to 3999 are supported, the range of the Roman numerals
+    pub fn cardinal(n: u32, case: &Case, gender: &Gender) -> Option<String> {
+        let parts = Latin::numeral_parts(n, &NumeralKind::Cardinal)?;
+
+        let declined: Vec<String> = parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| (part.as_str(), Latin::numeral_part_gender(&parts, i, gender)))
+            .map(|(part, gender)| match part {
+                "unus" => Latin::guess_pronominal_adjective(part, case, &Number::Singular, &gender),
+                "duo" => Latin::numeral_forms(&DUO_FORMS, case, &gender),
+                "tres" => Latin::numeral_forms(&TRES_FORMS, case, &gender),
+                "milia" => MILIA_ENDINGS.ending(case, &Number::Plural).to_string(),
+                _ if part.ends_with("enti") => {
+                    let stem = part.strip_suffix('i').unwrap_or(part);
+                    format!("{stem}{}", Latin::us_ending(case, &Number::Plural, &gender))
+                }
+                _ => part.to_string(),
+            })
+            .collect();
+
+        Some(declined.join(" "))
+    }
+
+    pub fn ordinal(n: u32, case: &Case, number: &Number, gender: &Gender) -> Option<String> {
+        let parts = Latin::numeral_parts(n, &NumeralKind::Ordinal)?;
+
+        Some(Latin::decline_numeral_parts(&parts, case, number, gender))
+    }
+
+    // Distributives are plural, "folia bina", "petala quina"
+    pub fn distributive(n: u32, case: &Case, gender: &Gender) -> Option<String> {
+        let parts = Latin::numeral_parts(n, &NumeralKind::Distributive)?;
+
+        Some(Latin::decline_numeral_parts(
+            &parts,
+            case,
+            &Number::Plural,
+            gender,
+        ))
+    }
+
+    pub fn multiplicative(n: u32) -> Option<String> {
+        Some(Latin::numeral_parts(n, &NumeralKind::Multiplicative)?.join(" "))
+    }
+
+    // The numeral agreeing in gender with the head noun, singular for ordinals and plural otherwise
+    pub fn numeral(
+        &self,
+        n: u32,
+        kind: &NumeralKind,
+        head_noun: &str,
+        case: &Case,
+    ) -> Option<String> {
+        let gender = self.noun(head_noun, &Case::Nom, &Number::Singular).1;
+
+        match kind {
+            NumeralKind::Cardinal => Latin::cardinal(n, case, &gender),
+            NumeralKind::Ordinal => Latin::ordinal(n, case, &Number::Singular, &gender),
+            NumeralKind::Distributive => Latin::distributive(n, case, &gender),
+            NumeralKind::Multiplicative => Latin::multiplicative(n),
+        }
+    }
+
+    pub fn to_roman(n: u32) -> Option<String> {
+        if !(1..=3999).contains(&n) {
+            return None;
+        }
+
+        let mut remaining = n;
+        let mut roman = String::new();
+        for (value, letters) in ROMAN_NUMERALS {
+            while remaining >= value {
+                roman.push_str(letters);
+                remaining -= value;
+            }
+        }
+
+        Some(roman)
+    }
+
+    // Only accepts well formed numerals, "IIII" or "VX" give None
+    pub fn from_roman(numeral: &str) -> Option<u32> {
+        let numeral = numeral.trim().to_uppercase();
+        let mut rest = numeral.as_str();
+        let mut value = 0;
+
+        for (amount, letters) in ROMAN_NUMERALS {
+            while let Some(stripped) = rest.strip_prefix(letters) {
+                value += amount;
+                rest = stripped;
+            }
+        }
+
+        match rest.is_empty() && Latin::to_roman(value) == Some(numeral) {
+            true => Some(value),
+            false => None,
+        }
+    }
+
+    // Splits a number into the lemmas it is spoken with, "28" -> ["duodetriginta"]
+    fn numeral_parts(n: u32, kind: &NumeralKind) -> Option<Vec<String>> {
+        if !(1..=3999).contains(&n) {
+            return None;
+        }
+
+        let (units, teens, tens, hundreds) = match kind {
+            NumeralKind::Cardinal => (
+                &CARDINAL_UNITS,
+                &CARDINAL_TEENS,
+                &CARDINAL_TENS,
+                &CARDINAL_HUNDREDS,
+            ),
+            NumeralKind::Ordinal => (
+                &ORDINAL_UNITS,
+                &ORDINAL_TEENS,
+                &ORDINAL_TENS,
+                &ORDINAL_HUNDREDS,
+            ),
+            NumeralKind::Distributive => (
+                &DISTRIBUTIVE_UNITS,
+                &DISTRIBUTIVE_TEENS,
+                &DISTRIBUTIVE_TENS,
+                &DISTRIBUTIVE_HUNDREDS,
+            ),
+            NumeralKind::Multiplicative => (
+                &MULTIPLICATIVE_UNITS,
+                &MULTIPLICATIVE_TEENS,
+                &MULTIPLICATIVE_TENS,
+                &MULTIPLICATIVE_HUNDREDS,
+            ),
+        };
+
+        let mut parts: Vec<&str> = Vec::new();
+        let subtractive;
+        let thousands = (n / 1000) as usize;
+        if thousands > 0 {
+            match kind {
+                NumeralKind::Cardinal if thousands == 1 => parts.push("mille"),
+                NumeralKind::Cardinal => parts.extend([CARDINAL_UNITS[thousands], "milia"]),
+                NumeralKind::Ordinal if thousands == 1 => parts.push("millesimus"),
+                NumeralKind::Ordinal => {
+                    parts.extend([MULTIPLICATIVE_UNITS[thousands], "millesimus"])
+                }
+                NumeralKind::Distributive => parts.extend([units[thousands], "milia"]),
+                NumeralKind::Multiplicative if thousands == 1 => parts.push("milies"),
+                NumeralKind::Multiplicative => {
+                    parts.extend([MULTIPLICATIVE_UNITS[thousands], "milies"])
+                }
+            }
+        }
+
+        let hundred = (n % 1000 / 100) as usize;
+        if hundred > 0 {
+            parts.push(hundreds[hundred]);
+        }
+
+        let ten = (n % 100 / 10) as usize;
+        let unit = (n % 10) as usize;
+        match (ten, unit) {
+            (0, 0) => {}
+            (0, _) => parts.push(units[unit]),
+            (1, 0) => parts.push(tens[1]),
+            // 18 and 19, 28 and 29 ... are counted down from the next ten
+            (1..=8, 8 | 9) => {
+                let prefix = if unit == 8 { "duode" } else { "unde" };
+                subtractive = format!("{prefix}{}", tens[ten + 1]);
+                parts.push(&subtractive);
+            }
+            (1, _) => parts.extend(teens[unit].split(' ')),
+            (_, 0) => parts.push(tens[ten]),
+            (_, _) => parts.extend([tens[ten], units[unit]]),
+        }
+
+        Some(parts.iter().map(|part| part.to_string()).collect())
+    }
+
+    // The multiplier of "milia" agrees with it in the neuter, "duo milia", "bina milia"
+    fn numeral_part_gender(parts: &[String], i: usize, gender: &Gender) -> Gender {
+        match parts.get(i + 1) {
+            Some(next) if next == "milia" => Gender::Neuter,
+            _ => gender.agreement(),
+        }
+    }
+
+    fn numeral_forms(forms: &[CaseEndings; 3], case: &Case, gender: &Gender) -> String {
+        let forms = match gender {
+            Gender::Feminine => &forms[1],
+            Gender::Neuter => &forms[2],
+            _ => &forms[0],
+        };
+
+        forms.ending(case, &Number::Plural).to_string()
+    }
+
+    fn us_ending(case: &Case, number: &Number, gender: &Gender) -> &'static str {
+        match gender {
+            Gender::Feminine => A_DECLENSION_ENDINGS.ending(case, number),
+            Gender::Neuter => UM_DECLENSION_ENDINGS.ending(case, number),
+            _ => US_DECLENSION_ENDINGS.ending(case, number),
+        }
+    }
+
+    // Every -us lemma is declined, adverbs and "milia" are handled on their own
+    fn decline_numeral_parts(
+        parts: &[String],
+        case: &Case,
+        number: &Number,
+        gender: &Gender,
+    ) -> String {
+        let declined: Vec<String> = parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| match part.strip_suffix("us") {
+                Some(stem) => {
+                    let gender = Latin::numeral_part_gender(parts, i, gender);
+                    format!("{stem}{}", Latin::us_ending(case, number, &gender))
+                }
+                None if part == "milia" => MILIA_ENDINGS.ending(case, &Number::Plural).to_string(),
+                None => part.to_string(),
+            })
+            .collect();
+
+        declined.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subtractive_numerals() {
+        let cardinal = |n| Latin::cardinal(n, &Case::Nom, &Gender::Neuter);
+        assert_eq!(cardinal(18), Some("duodeviginti".to_string()));
+        assert_eq!(cardinal(19), Some("undeviginti".to_string()));
+        assert_eq!(cardinal(28), Some("duodetriginta".to_string()));
+        assert_eq!(
+            Latin::ordinal(18, &Case::Nom, &Number::Singular, &Gender::Masculine),
+            Some("duodevicesimus".to_string())
+        );
+    }
+
+    #[test]
+    fn milia_is_a_neuter_noun() {
+        assert_eq!(
+            Latin::cardinal(3000, &Case::Nom, &Gender::Feminine),
+            Some("tria milia".to_string())
+        );
+        assert_eq!(
+            Latin::cardinal(2000, &Case::Gen, &Gender::Masculine),
+            Some("duorum milium".to_string())
+        );
+        assert_eq!(
+            Latin::cardinal(1000, &Case::Gen, &Gender::Masculine),
+            Some("mille".to_string())
+        );
+    }
+
+    #[test]
+    fn roman_numerals() {
+        for n in [1, 4, 9, 14, 40, 90, 400, 1994, 2024, 3999] {
+            let roman = Latin::to_roman(n).unwrap();
+            assert_eq!(Latin::from_roman(&roman), Some(n));
+        }
+        assert_eq!(Latin::to_roman(1994), Some("MCMXCIV".to_string()));
+        assert_eq!(Latin::from_roman("xiv"), Some(14));
+        assert_eq!(Latin::from_roman("IIII"), None);
+        assert_eq!(Latin::from_roman("VX"), None);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(Latin::to_roman(0), None);
+        assert_eq!(Latin::to_roman(4000), None);
+        assert_eq!(Latin::cardinal(0, &Case::Nom, &Gender::Masculine), None);
+        assert_eq!(Latin::cardinal(4000, &Case::Nom, &Gender::Masculine), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Government {
+    Accusative,
+    Ablative,
+    // Accusative of motion towards, ablative of place where, "in", "sub"
+    AccusativeOrAblative,
+}
+
+pub const PREPOSITIONS: [(&str, Government); 40] = [
+    ("ad", Government::Accusative),
+    ("adversus", Government::Accusative),
+    ("ante", Government::Accusative),
+    ("apud", Government::Accusative),
+    ("circa", Government::Accusative),
+    ("circum", Government::Accusative),
+    ("cis", Government::Accusative),
+    ("contra", Government::Accusative),
+    ("erga", Government::Accusative),
+    ("extra", Government::Accusative),
+    ("infra", Government::Accusative),
+    ("inter", Government::Accusative),
+    ("intra", Government::Accusative),
+    ("iuxta", Government::Accusative),
+    ("ob", Government::Accusative),
+    ("penes", Government::Accusative),
+    ("per", Government::Accusative),
+    ("post", Government::Accusative),
+    ("praeter", Government::Accusative),
+    ("prope", Government::Accusative),
+    ("propter", Government::Accusative),
+    ("secundum", Government::Accusative),
+    ("supra", Government::Accusative),
+    ("trans", Government::Accusative),
+    ("ultra", Government::Accusative),
+    ("usque ad", Government::Accusative),
+    ("a", Government::Ablative),
+    ("ab", Government::Ablative),
+    ("abs", Government::Ablative),
+    ("coram", Government::Ablative),
+    ("cum", Government::Ablative),
+    ("de", Government::Ablative),
+    ("e", Government::Ablative),
+    ("ex", Government::Ablative),
+    ("prae", Government::Ablative),
+    ("pro", Government::Ablative),
+    ("sine", Government::Ablative),
+    ("in", Government::AccusativeOrAblative),
+    ("sub", Government::AccusativeOrAblative),
+    ("super", Government::AccusativeOrAblative),
+];
+
+impl Latin {
+    pub fn preposition_government(preposition: &str) -> Option<Government> {
+        PREPOSITIONS
+            .iter()
+            .find(|(word, _)| *word == preposition)
+            .map(|(_, government)| government.clone())
+    }
+
+    // The case a preposition governs, motion only matters for "in", "sub" and "super"
+    pub fn governed_case(preposition: &str, motion: bool) -> Option<Case> {
+        match Latin::preposition_government(preposition)? {
+            Government::Accusative => Some(Case::Acc),
+            Government::Ablative => Some(Case::Abl),
+            Government::AccusativeOrAblative if motion => Some(Case::Acc),
+            Government::AccusativeOrAblative => Some(Case::Abl),
+        }
+    }
+
+    // "a" and "ab", "e" and "ex" alternate on the sound of the following word
+    pub fn preposition_form(preposition: &str, next_word: &str) -> String {
+        let before_vowel = next_word
+            .to_lowercase()
+            .starts_with(['a', 'e', 'i', 'o', 'u', 'y', 'h']);
+
+        match preposition {
+            "a" | "ab" if before_vowel => "ab".to_string(),
+            "a" | "ab" => "a".to_string(),
+            "e" if before_vowel => "ex".to_string(),
+            _ => preposition.to_string(),
+        }
+    }
+
+    // "sub foliis latis", None when the preposition is unknown
+    pub fn prepositional_phrase(
+        &self,
+        preposition: &str,
+        complex_nomen: &ComplexNoun,
+        number: &Number,
+        motion: bool,
+    ) -> Option<String> {
+        let case = Latin::governed_case(preposition, motion)?;
+        let phrase = self.complex_noun(complex_nomen, &case, number);
+
+        Some(format!(
+            "{} {}",
+            Latin::preposition_form(preposition, &phrase),
+            phrase
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    fn phrase(head_noun: &str, adjective: &[&str]) -> ComplexNoun {
+        ComplexNoun {
+            head_noun: head_noun.to_string(),
+            adjective: adjective.iter().map(|a| a.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn place_where_and_motion_towards() {
+        let latin = latin();
+        assert_eq!(
+            latin.prepositional_phrase(
+                "sub",
+                &phrase("folium", &["latus"]),
+                &Number::Plural,
+                false
+            ),
+            Some("sub foliis latis".to_string())
+        );
+        assert_eq!(
+            latin.prepositional_phrase("in", &phrase("silva", &[]), &Number::Singular, true),
+            Some("in silvam".to_string())
+        );
+        assert_eq!(
+            latin.prepositional_phrase("in", &phrase("silva", &[]), &Number::Singular, false),
+            Some("in silva".to_string())
+        );
+    }
+
+    #[test]
+    fn governed_cases() {
+        assert_eq!(Latin::governed_case("sine", true), Some(Case::Abl));
+        assert_eq!(Latin::governed_case("ad", false), Some(Case::Acc));
+        assert_eq!(Latin::governed_case("apud nos", false), None);
+        assert_eq!(
+            latin().prepositional_phrase("foo", &phrase("silva", &[]), &Number::Singular, false),
+            None
+        );
+    }
+
+    #[test]
+    fn a_and_ab() {
+        assert_eq!(Latin::preposition_form("a", "foliis"), "a");
+        assert_eq!(Latin::preposition_form("a", "apice"), "ab");
+        assert_eq!(Latin::preposition_form("e", "apice"), "ex");
+    }
+}
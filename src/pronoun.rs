@@ -0,0 +1,525 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PronounKind {
+    // ego, tu, and "is, ea, id" for the third person
+    Personal,
+    // sui, sibi, se, the first and second persons borrow the personal forms
+    Reflexive,
+    Hic,
+    Ille,
+    Iste,
+    Is,
+    Ipse,
+    Idem,
+    // qui, quae, quod
+    Relative,
+    // quis, quid
+    Interrogative,
+}
+
+// Pronouns that can stand as adjectives in a ComplexNoun
+pub const DEMONSTRATIVES: [(&str, PronounKind); 6] = [
+    ("hic", PronounKind::Hic),
+    ("ille", PronounKind::Ille),
+    ("iste", PronounKind::Iste),
+    ("is", PronounKind::Is),
+    ("ipse", PronounKind::Ipse),
+    ("idem", PronounKind::Idem),
+];
+
+// Personal and reflexive pronouns do not vary for gender
+pub const EGO_FORMS: CaseEndings = CaseEndings {
+    gender: Gender::Masculine,
+    nom_sg: "ego",
+    acc_sg: "me",
+    gen_sg: "mei",
+    dat_sg: "mihi",
+    abl_sg: "me",
+
+    nom_pl: "nos",
+    acc_pl: "nos",
+    gen_pl: "nostrum",
+    dat_pl: "nobis",
+    abl_pl: "nobis",
+};
+
+pub const TU_FORMS: CaseEndings = CaseEndings {
+    gender: Gender::Masculine,
+    nom_sg: "tu",
+    acc_sg: "te",
+    gen_sg: "tui",
+    dat_sg: "tibi",
+    abl_sg: "te",
+
+    nom_pl: "vos",
+    acc_pl: "vos",
+    gen_pl: "vestrum",
+    dat_pl: "vobis",
+    abl_pl: "vobis",
+};
+
+// The reflexive has no nominative, left empty, and the same forms in both numbers
+pub const SUI_FORMS: CaseEndings = CaseEndings {
+    gender: Gender::Masculine,
+    nom_sg: "",
+    acc_sg: "se",
+    gen_sg: "sui",
+    dat_sg: "sibi",
+    abl_sg: "se",
+
+    nom_pl: "",
+    acc_pl: "se",
+    gen_pl: "sui",
+    dat_pl: "sibi",
+    abl_pl: "se",
+};
+
+pub const IS_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "is",
+        acc_sg: "eum",
+        gen_sg: "eius",
+        dat_sg: "ei",
+        abl_sg: "eo",
+
+        nom_pl: "ei",
+        acc_pl: "eos",
+        gen_pl: "eorum",
+        dat_pl: "eis",
+        abl_pl: "eis",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "ea",
+        acc_sg: "eam",
+        gen_sg: "eius",
+        dat_sg: "ei",
+        abl_sg: "ea",
+
+        nom_pl: "eae",
+        acc_pl: "eas",
+        gen_pl: "earum",
+        dat_pl: "eis",
+        abl_pl: "eis",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "id",
+        acc_sg: "id",
+        gen_sg: "eius",
+        dat_sg: "ei",
+        abl_sg: "eo",
+
+        nom_pl: "ea",
+        acc_pl: "ea",
+        gen_pl: "eorum",
+        dat_pl: "eis",
+        abl_pl: "eis",
+    },
+];
+
+pub const HIC_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "hic",
+        acc_sg: "hunc",
+        gen_sg: "huius",
+        dat_sg: "huic",
+        abl_sg: "hoc",
+
+        nom_pl: "hi",
+        acc_pl: "hos",
+        gen_pl: "horum",
+        dat_pl: "his",
+        abl_pl: "his",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "haec",
+        acc_sg: "hanc",
+        gen_sg: "huius",
+        dat_sg: "huic",
+        abl_sg: "hac",
+
+        nom_pl: "hae",
+        acc_pl: "has",
+        gen_pl: "harum",
+        dat_pl: "his",
+        abl_pl: "his",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "hoc",
+        acc_sg: "hoc",
+        gen_sg: "huius",
+        dat_sg: "huic",
+        abl_sg: "hoc",
+
+        nom_pl: "haec",
+        acc_pl: "haec",
+        gen_pl: "horum",
+        dat_pl: "his",
+        abl_pl: "his",
+    },
+];
+
+pub const ILLE_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "ille",
+        acc_sg: "illum",
+        gen_sg: "illius",
+        dat_sg: "illi",
+        abl_sg: "illo",
+
+        nom_pl: "illi",
+        acc_pl: "illos",
+        gen_pl: "illorum",
+        dat_pl: "illis",
+        abl_pl: "illis",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "illa",
+        acc_sg: "illam",
+        gen_sg: "illius",
+        dat_sg: "illi",
+        abl_sg: "illa",
+
+        nom_pl: "illae",
+        acc_pl: "illas",
+        gen_pl: "illarum",
+        dat_pl: "illis",
+        abl_pl: "illis",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "illud",
+        acc_sg: "illud",
+        gen_sg: "illius",
+        dat_sg: "illi",
+        abl_sg: "illo",
+
+        nom_pl: "illa",
+        acc_pl: "illa",
+        gen_pl: "illorum",
+        dat_pl: "illis",
+        abl_pl: "illis",
+    },
+];
+
+pub const ISTE_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "iste",
+        acc_sg: "istum",
+        gen_sg: "istius",
+        dat_sg: "isti",
+        abl_sg: "isto",
+
+        nom_pl: "isti",
+        acc_pl: "istos",
+        gen_pl: "istorum",
+        dat_pl: "istis",
+        abl_pl: "istis",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "ista",
+        acc_sg: "istam",
+        gen_sg: "istius",
+        dat_sg: "isti",
+        abl_sg: "ista",
+
+        nom_pl: "istae",
+        acc_pl: "istas",
+        gen_pl: "istarum",
+        dat_pl: "istis",
+        abl_pl: "istis",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "istud",
+        acc_sg: "istud",
+        gen_sg: "istius",
+        dat_sg: "isti",
+        abl_sg: "isto",
+
+        nom_pl: "ista",
+        acc_pl: "ista",
+        gen_pl: "istorum",
+        dat_pl: "istis",
+        abl_pl: "istis",
+    },
+];
+
+pub const IPSE_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "ipse",
+        acc_sg: "ipsum",
+        gen_sg: "ipsius",
+        dat_sg: "ipsi",
+        abl_sg: "ipso",
+
+        nom_pl: "ipsi",
+        acc_pl: "ipsos",
+        gen_pl: "ipsorum",
+        dat_pl: "ipsis",
+        abl_pl: "ipsis",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "ipsa",
+        acc_sg: "ipsam",
+        gen_sg: "ipsius",
+        dat_sg: "ipsi",
+        abl_sg: "ipsa",
+
+        nom_pl: "ipsae",
+        acc_pl: "ipsas",
+        gen_pl: "ipsarum",
+        dat_pl: "ipsis",
+        abl_pl: "ipsis",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "ipsum",
+        acc_sg: "ipsum",
+        gen_sg: "ipsius",
+        dat_sg: "ipsi",
+        abl_sg: "ipso",
+
+        nom_pl: "ipsa",
+        acc_pl: "ipsa",
+        gen_pl: "ipsorum",
+        dat_pl: "ipsis",
+        abl_pl: "ipsis",
+    },
+];
+
+pub const IDEM_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "idem",
+        acc_sg: "eundem",
+        gen_sg: "eiusdem",
+        dat_sg: "eidem",
+        abl_sg: "eodem",
+
+        nom_pl: "eidem",
+        acc_pl: "eosdem",
+        gen_pl: "eorundem",
+        dat_pl: "eisdem",
+        abl_pl: "eisdem",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "eadem",
+        acc_sg: "eandem",
+        gen_sg: "eiusdem",
+        dat_sg: "eidem",
+        abl_sg: "eadem",
+
+        nom_pl: "eaedem",
+        acc_pl: "easdem",
+        gen_pl: "earundem",
+        dat_pl: "eisdem",
+        abl_pl: "eisdem",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "idem",
+        acc_sg: "idem",
+        gen_sg: "eiusdem",
+        dat_sg: "eidem",
+        abl_sg: "eodem",
+
+        nom_pl: "eadem",
+        acc_pl: "eadem",
+        gen_pl: "eorundem",
+        dat_pl: "eisdem",
+        abl_pl: "eisdem",
+    },
+];
+
+pub const QUI_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "qui",
+        acc_sg: "quem",
+        gen_sg: "cuius",
+        dat_sg: "cui",
+        abl_sg: "quo",
+
+        nom_pl: "qui",
+        acc_pl: "quos",
+        gen_pl: "quorum",
+        dat_pl: "quibus",
+        abl_pl: "quibus",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "quae",
+        acc_sg: "quam",
+        gen_sg: "cuius",
+        dat_sg: "cui",
+        abl_sg: "qua",
+
+        nom_pl: "quae",
+        acc_pl: "quas",
+        gen_pl: "quarum",
+        dat_pl: "quibus",
+        abl_pl: "quibus",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "quod",
+        acc_sg: "quod",
+        gen_sg: "cuius",
+        dat_sg: "cui",
+        abl_sg: "quo",
+
+        nom_pl: "quae",
+        acc_pl: "quae",
+        gen_pl: "quorum",
+        dat_pl: "quibus",
+        abl_pl: "quibus",
+    },
+];
+
+// The interrogative shares the plural of the relative
+pub const QUIS_FORMS: [CaseEndings; 3] = [
+    CaseEndings {
+        gender: Gender::Masculine,
+        nom_sg: "quis",
+        acc_sg: "quem",
+        gen_sg: "cuius",
+        dat_sg: "cui",
+        abl_sg: "quo",
+
+        nom_pl: "qui",
+        acc_pl: "quos",
+        gen_pl: "quorum",
+        dat_pl: "quibus",
+        abl_pl: "quibus",
+    },
+    CaseEndings {
+        gender: Gender::Feminine,
+        nom_sg: "quis",
+        acc_sg: "quem",
+        gen_sg: "cuius",
+        dat_sg: "cui",
+        abl_sg: "quo",
+
+        nom_pl: "quae",
+        acc_pl: "quas",
+        gen_pl: "quarum",
+        dat_pl: "quibus",
+        abl_pl: "quibus",
+    },
+    CaseEndings {
+        gender: Gender::Neuter,
+        nom_sg: "quid",
+        acc_sg: "quid",
+        gen_sg: "cuius",
+        dat_sg: "cui",
+        abl_sg: "quo",
+
+        nom_pl: "quae",
+        acc_pl: "quae",
+        gen_pl: "quorum",
+        dat_pl: "quibus",
+        abl_pl: "quibus",
+    },
+];
+
+impl Latin {
+    // Person only matters for personal and reflexive pronouns, gender only for the others
+    pub fn pronoun(
+        kind: &PronounKind,
+        case: &Case,
+        number: &Number,
+        gender: &Gender,
+        person: &Person,
+    ) -> String {
+        // The reflexive of every person has no nominative, not even "ego" or "tu"
+        if kind == &PronounKind::Reflexive && case == &Case::Nom {
+            return String::new();
+        }
+
+        // Gender plays no part in the personal and reflexive forms
+        let personal = match (kind, person) {
+            (PronounKind::Personal | PronounKind::Reflexive, Person::First) => Some(&EGO_FORMS),
+            (PronounKind::Personal | PronounKind::Reflexive, Person::Second) => Some(&TU_FORMS),
+            (PronounKind::Reflexive, Person::Third) => Some(&SUI_FORMS),
+            _ => None,
+        };
+        if let Some(forms) = personal {
+            return forms.ending(case, number).to_string();
+        }
+
+        let forms = match kind {
+            PronounKind::Personal | PronounKind::Reflexive | PronounKind::Is => &IS_FORMS,
+            PronounKind::Hic => &HIC_FORMS,
+            PronounKind::Ille => &ILLE_FORMS,
+            PronounKind::Iste => &ISTE_FORMS,
+            PronounKind::Ipse => &IPSE_FORMS,
+            PronounKind::Idem => &IDEM_FORMS,
+            PronounKind::Relative => &QUI_FORMS,
+            PronounKind::Interrogative => &QUIS_FORMS,
+        };
+
+        let forms = match gender.agreement() {
+            Gender::Feminine => &forms[1],
+            Gender::Neuter => &forms[2],
+            _ => &forms[0],
+        };
+
+        forms.ending(case, number).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reflexive_has_no_nominative() {
+        for person in [Person::First, Person::Third] {
+            let nominative = Latin::pronoun(
+                &PronounKind::Reflexive,
+                &Case::Nom,
+                &Number::Singular,
+                &Gender::Masculine,
+                &person,
+            );
+            assert_eq!(nominative, "");
+        }
+
+        let accusative = Latin::pronoun(
+            &PronounKind::Reflexive,
+            &Case::Acc,
+            &Number::Plural,
+            &Gender::Feminine,
+            &Person::Third,
+        );
+        assert_eq!(accusative, "se");
+    }
+
+    #[test]
+    fn personal_pronouns_ignore_gender() {
+        for gender in [Gender::Masculine, Gender::Feminine, Gender::Neuter] {
+            let dative = Latin::pronoun(
+                &PronounKind::Personal,
+                &Case::Dat,
+                &Number::Singular,
+                &gender,
+                &Person::First,
+            );
+            assert_eq!(dative, "mihi");
+        }
+    }
+}
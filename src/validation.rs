@@ -0,0 +1,364 @@
+use crate::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum NameIssue {
+    // Letters with diacritics are transcribed, ICN Art. 60.7
+    Diacritic,
+    // Hyphens are dropped from compounds, ICN Art. 60.9
+    Hyphen,
+    // An adjectival epithet that does not agree with the gender of the genus, ICN Art. 23.5
+    GenderAgreement,
+    // A compound joined with the wrong vowel, ICN Art. 60.10
+    ConnectingVowel,
+    // A personal name in the genitive with the wrong termination, ICN Art. 60.12
+    EponymTermination,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Severity {
+    // Wrong under the Code
+    Error,
+    // Probably wrong, a guess from the spelling of the word
+    Warning,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct NameDiagnostic {
+    pub issue: NameIssue,
+    pub severity: Severity,
+    // The word as it stands once the corrections of the diagnostics before are applied
+    pub word: String,
+    pub suggestion: String,
+}
+
+// Endings that mark an epithet as an adjective even when it is not in the dictionary. Endings
+// that nouns share, as "-anus" in "platanus" or "-icus" in "ficus", are left out
+pub const ADJECTIVE_SUFFIXES: [&str; 3] = ["ensis", "osus", "ifer"];
+
+// Prefixes of Latin and Greek plant names whose last part is in the dictionary,
+// "chamaemorus", "epithymum"
+pub const NOUN_PREFIXES: [&str; 8] = [
+    "chamae", "epi", "hyper", "hypo", "para", "peri", "pseudo", "sub",
+];
+
+impl Latin {
+    // Checks a scientific name such as "Acer rubra var. glábra" and suggests corrections.
+    // Rank abbreviations are skipped, all the other words after the genus are epithets
+    pub fn validate_name(&self, name: &str) -> Vec<NameDiagnostic> {
+        let ranks = [
+            InfraspecificRank::Subspecies,
+            InfraspecificRank::Variety,
+            InfraspecificRank::Subvariety,
+            InfraspecificRank::Form,
+        ];
+        let words: Vec<&str> = name
+            .split_whitespace()
+            .filter(|w| !ranks.iter().any(|rank| rank.abbreviation() == *w))
+            .collect();
+
+        let mut diagnostics = Vec::new();
+        let Some((genus, epithets)) = words.split_first() else {
+            return diagnostics;
+        };
+
+        let genus = Latin::check_diacritics(genus, &mut diagnostics);
+        let gender = self.genus_gender(&genus).gender;
+
+        for epithet in epithets {
+            let mut epithet = Latin::check_diacritics(&epithet.to_lowercase(), &mut diagnostics);
+
+            if epithet.contains('-') {
+                if !self.hyphen_allowed(&epithet) {
+                    let suggestion = epithet.replace('-', "");
+                    diagnostics.push(NameDiagnostic {
+                        issue: NameIssue::Hyphen,
+                        severity: Severity::Error,
+                        word: epithet,
+                        suggestion: suggestion.clone(),
+                    });
+                    epithet = suggestion;
+                } else {
+                    continue;
+                }
+            }
+
+            match self.epithet_lemma(&epithet) {
+                Some(lemma) => self.check_adjectival(&epithet, &lemma, &gender, &mut diagnostics),
+                None => self.check_eponym(&epithet, &mut diagnostics),
+            }
+        }
+
+        diagnostics
+    }
+
+    fn check_diacritics(word: &str, diagnostics: &mut Vec<NameDiagnostic>) -> String {
+        let plain = Latin::transcribe_diacritics(word);
+        if plain != word {
+            diagnostics.push(NameDiagnostic {
+                issue: NameIssue::Diacritic,
+                severity: Severity::Error,
+                word: word.to_string(),
+                suggestion: plain.clone(),
+            });
+        }
+
+        plain
+    }
+
+    // Epithets made of words that stand on their own keep the hyphen, "uva-ursi",
+    // "novae-angliae". A first part ending in a connecting vowel is a compound, "albo-marginatus"
+    fn hyphen_allowed(&self, word: &str) -> bool {
+        let Some((first, _)) = word.split_once('-') else {
+            return true;
+        };
+
+        !first.ends_with(['i', 'o'])
+            || self.noun_map.contains_key(first)
+            || self.adj_map.contains_key(first)
+    }
+
+    // Masculine lemma of an adjectival epithet in the nominative singular, "rubra" gives "ruber".
+    // None for nouns in apposition and in the genitive. A word that is both a noun and the lemma
+    // of an adjective is taken as a noun, as "malus" in "Pyrus malus"
+    pub fn epithet_lemma(&self, word: &str) -> Option<String> {
+        if self.noun_map.contains_key(word) && self.adj_map.contains_key(word) {
+            return None;
+        }
+
+        let mut candidates = Vec::new();
+        if word.ends_with("us") || word.ends_with("is") || word.ends_with("er") {
+            candidates.push(word.to_string());
+        }
+        for ending in ["a", "um"] {
+            if let Some(stem) = word.strip_suffix(ending) {
+                candidates.push(format!("{stem}us"));
+                if let Some(stem) = stem.strip_suffix('r') {
+                    candidates.push(format!("{stem}er"));
+                }
+            }
+        }
+        if let Some(stem) = word.strip_suffix('e') {
+            candidates.push(format!("{stem}is"));
+        }
+
+        candidates.into_iter().find(|lemma| {
+            let known = self.adj_map.contains_key(lemma)
+                || ADJECTIVE_SUFFIXES.iter().any(|s| lemma.ends_with(s))
+                || Latin::compound_parts(lemma).is_some();
+
+            known
+                && [Gender::Masculine, Gender::Feminine, Gender::Neuter]
+                    .iter()
+                    .any(|g| {
+                        self.adjective(lemma, &Case::Nom, &Number::Singular, g, &Degree::Positive)
+                            == word
+                    })
+        })
+    }
+
+    fn check_adjectival(
+        &self,
+        word: &str,
+        lemma: &str,
+        gender: &Gender,
+        diagnostics: &mut Vec<NameDiagnostic>,
+    ) {
+        // Any of the genders a genus is listed with will do, and any at all when it is unknown
+        let allowed = match gender {
+            Gender::Ambiguous([]) => &[Gender::Masculine, Gender::Feminine, Gender::Neuter][..],
+            Gender::Ambiguous(genders) => genders,
+            gender => std::slice::from_ref(gender),
+        };
+        let gender = allowed
+            .iter()
+            .find(|gender| {
+                self.adjective(
+                    lemma,
+                    &Case::Nom,
+                    &Number::Singular,
+                    gender,
+                    &Degree::Positive,
+                ) == word
+            })
+            .unwrap_or(&allowed[0]);
+
+        let agreeing = |lemma: &str| {
+            self.adjective(
+                lemma,
+                &Case::Nom,
+                &Number::Singular,
+                gender,
+                &Degree::Positive,
+            )
+        };
+
+        let expected = agreeing(lemma);
+        if expected != word {
+            diagnostics.push(NameDiagnostic {
+                issue: NameIssue::GenderAgreement,
+                severity: Severity::Error,
+                word: word.to_string(),
+                suggestion: expected.clone(),
+            });
+        }
+
+        let Some((stem, second)) = Latin::compound_parts(lemma) else {
+            return;
+        };
+        if let Some(compound) = Latin::compound_epithet(stem, second) {
+            if compound.word != lemma {
+                diagnostics.push(NameDiagnostic {
+                    issue: NameIssue::ConnectingVowel,
+                    severity: Severity::Error,
+                    word: expected,
+                    suggestion: agreeing(&compound.word),
+                });
+            }
+        }
+    }
+
+    // First element stem and second element of a compound epithet joined by vowels,
+    // "latifolius" gives "lat" and "folius". None when either element is unknown, as in
+    // "caprifolius"
+    fn compound_parts(lemma: &str) -> Option<(&'static str, &'static str)> {
+        COMPOUND_SECOND_ELEMENTS.iter().find_map(|(_, second, _)| {
+            let prefix = lemma.strip_suffix(second)?;
+            COMPOUND_FIRST_ELEMENTS.iter().find_map(|(_, stem, _)| {
+                let vowels = prefix.strip_prefix(stem)?;
+                vowels
+                    .chars()
+                    .all(|c| "aeiouy".contains(c))
+                    .then_some((*stem, *second))
+            })
+        })
+    }
+
+    // "hookerii" for "hookeri", "smithi" for "smithii", ICN Art. 60.12 and Rec. 60C.1. The
+    // genitive is compared with the one Latin::commemorative_epithet forms from the name it
+    // would commemorate
+    fn check_eponym(&self, word: &str, diagnostics: &mut Vec<NameDiagnostic>) {
+        if self.latin_genitive(word) {
+            return;
+        }
+
+        // The personal name and honorees for each termination, the longest one first
+        let man = [Gender::Masculine];
+        let woman = [Gender::Feminine];
+        let men = [Gender::Masculine, Gender::Masculine];
+        let women = [Gender::Feminine, Gender::Feminine];
+        let terminations: [(&[&str], &str, &[Gender]); 6] = [
+            (&["ii", "i"], "", &man),
+            (&["iae", "ae"], "", &woman),
+            (&["ae"], "a", &man),
+            (&["iorum", "orum"], "", &men),
+            (&["iarum", "arum"], "", &women),
+            (&["arum"], "a", &men),
+        ];
+
+        let candidates: Vec<String> = terminations
+            .iter()
+            .filter_map(|(endings, restored, honorees)| {
+                let ending = endings.iter().find(|ending| word.ends_with(*ending))?;
+                let name = format!("{}{restored}", &word[..word.len() - ending.len()]);
+                (!name.is_empty()).then(|| Latin::commemorative_epithet(&name, honorees))
+            })
+            .collect();
+
+        if let Some(suggestion) = candidates.first() {
+            if !candidates.iter().any(|candidate| candidate == word) {
+                diagnostics.push(NameDiagnostic {
+                    issue: NameIssue::EponymTermination,
+                    severity: Severity::Error,
+                    word: word.to_string(),
+                    suggestion: suggestion.clone(),
+                });
+            }
+        }
+    }
+
+    // The genitive of a noun in the dictionary, "thymi", "hortorum", or of a compound ending
+    // in one, "epithymi", "chamaemori"
+    fn latin_genitive(&self, word: &str) -> bool {
+        let endings: [(&str, &[&str]); 4] = [
+            ("i", &["us", "um", "er", "ius", "ium"]),
+            ("orum", &["us", "um"]),
+            ("ae", &["a"]),
+            ("arum", &["a"]),
+        ];
+
+        std::iter::once(word)
+            .chain(NOUN_PREFIXES.iter().filter_map(|p| word.strip_prefix(p)))
+            .any(|word| {
+                self.noun_map.contains_key(word)
+                    || endings.iter().any(|(ending, lemmas)| {
+                        word.strip_suffix(ending).is_some_and(|stem| {
+                            lemmas
+                                .iter()
+                                .any(|lemma| self.noun_map.contains_key(&format!("{stem}{lemma}")))
+                        })
+                    })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::latin;
+
+    #[test]
+    fn nouns_in_apposition_are_not_adjectives() {
+        let latin = latin();
+        assert_eq!(latin.validate_name("Acer pseudoplatanus"), Vec::new());
+        assert_eq!(latin.validate_name("Lonicera caprifolium"), Vec::new());
+        assert_eq!(latin.validate_name("Pyrus malus"), Vec::new());
+        assert_eq!(latin.validate_name("Ginkgo biloba"), Vec::new());
+    }
+
+    #[test]
+    fn adjectival_epithets_agree_with_the_genus() {
+        let latin = latin();
+        let diagnostics = latin.validate_name("Acer rubra");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].issue, NameIssue::GenderAgreement);
+        assert_eq!(diagnostics[0].suggestion, "rubrum");
+
+        let diagnostics = latin.validate_name("Quercus latofolia");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].issue, NameIssue::ConnectingVowel);
+        assert_eq!(diagnostics[0].suggestion, "latifolia");
+    }
+
+    #[test]
+    fn genitives_of_latin_nouns_are_not_eponyms() {
+        let latin = latin();
+        assert_eq!(latin.validate_name("Cuscuta epithymi"), Vec::new());
+        assert_eq!(latin.validate_name("Rubus chamaemori"), Vec::new());
+        assert_eq!(latin.validate_name("Carex hortorum"), Vec::new());
+    }
+
+    #[test]
+    fn eponyms_take_the_recommended_termination() {
+        let latin = latin();
+        for (name, suggestion) in [
+            ("Rhododendron brouni", "brounii"),
+            ("Salix smithi", "smithii"),
+            ("Rhododendron forresti", "forrestii"),
+        ] {
+            let diagnostics = latin.validate_name(name);
+            assert_eq!(diagnostics.len(), 1, "{name}");
+            assert_eq!(diagnostics[0].issue, NameIssue::EponymTermination);
+            assert_eq!(diagnostics[0].severity, Severity::Error);
+            assert_eq!(diagnostics[0].suggestion, suggestion);
+        }
+
+        for name in ["Berberis thunbergii", "Clematis trianae", "Acer fortunei"] {
+            assert_eq!(latin.validate_name(name), Vec::new(), "{name}");
+        }
+
+        let diagnostics = latin.validate_name("Salix hookerii");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].suggestion, "hookeri");
+    }
+}